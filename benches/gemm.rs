@@ -0,0 +1,73 @@
+//! Compares the blocked `Matrix::mult` against a naive triple loop over the same matrices.
+//!
+//! Run with `cargo bench --bench gemm`.
+
+use std::{
+    hint::black_box,
+    time::{Duration, Instant},
+};
+
+use sickmath::Matrix;
+
+/// `C = AB` with a plain triple loop over the rows, the way `Matrix::mult` worked before blocking
+fn naive<const N: usize>(a: &Matrix<f64, N, N>, b: &Matrix<f64, N, N>) -> Matrix<f64, N, N> {
+    let mut c: Matrix<f64, N, N> = Matrix::default();
+
+    for row in 0..N {
+        for col in 0..N {
+            let mut acc = 0.0;
+            for idx in 0..N {
+                acc += a[row][idx] * b[idx][col];
+            }
+            c[row][col] = acc;
+        }
+    }
+
+    c
+}
+
+/// Fastest of a few runs, after one warm up run
+fn time(mut f: impl FnMut()) -> Duration {
+    f();
+
+    (0..5)
+        .map(|_| {
+            let start = Instant::now();
+            f();
+            start.elapsed()
+        })
+        .min()
+        .unwrap_or_default()
+}
+
+fn bench<const N: usize>() {
+    let a: Matrix<f64, N, N> = Matrix::new_random();
+    let b: Matrix<f64, N, N> = Matrix::new_random();
+
+    let naive_time = time(|| {
+        black_box(naive(&a, &b));
+    });
+    let blocked_time = time(|| {
+        black_box(a.mult(&b));
+    });
+
+    println!(
+        "{:>6} {:>10.2}ms {:>10.2}ms {:>7.1}x",
+        N,
+        naive_time.as_secs_f64() * 1e3,
+        blocked_time.as_secs_f64() * 1e3,
+        naive_time.as_secs_f64() / blocked_time.as_secs_f64()
+    );
+}
+
+fn main() {
+    println!(
+        "{:>6} {:>12} {:>12} {:>8}",
+        "size", "naive", "blocked", "speedup"
+    );
+
+    bench::<64>();
+    bench::<128>();
+    bench::<256>();
+    bench::<512>();
+}
@@ -0,0 +1,469 @@
+mod softmax;
+
+use num::Float;
+
+use crate::{matrix::Matrix, vector::Vector};
+use softmax::Normalizer;
+
+/// Neural network activation functions.
+///
+/// Every function is applied to each item, except for softmax and log-softmax which normalize a
+/// whole `Vector` and every row of a `Matrix` on its own. The `_derivative` methods return the
+/// derivative of the function at every item of `self`, so the gradient with respect to the input
+/// is the incoming gradient multiplied entrywise with it.
+///
+/// ## Mutable methods
+/// Like in `MathVector`, every function has a `_mut` alternative which overwrites `self` instead
+/// of allocating a new value.
+/// ```rust
+/// # use sickmath::*;
+/// let mut vector: Vector<f64, 3> = Vector::new([-1.0, 0.0, 2.0]);
+///
+/// assert_eq!(vector.relu(), Vector::new([0.0, 0.0, 2.0]));
+/// assert_eq!(vector.relu_derivative(), Vector::new([0.0, 0.0, 1.0]));
+///
+/// vector.leaky_relu_mut(0.1);
+/// assert_eq!(vector, Vector::new([-0.1, 0.0, 2.0]));
+/// ```
+pub trait Activation<T>: Clone
+where
+    T: Float,
+{
+    /// Replaces every item with `f(item)`
+    fn map_mut(&mut self, f: impl Fn(T) -> T);
+
+    /// Mutable softmax
+    fn softmax_mut(&mut self);
+
+    /// Mutable log-softmax
+    fn log_softmax_mut(&mut self);
+
+    /// Gradient of softmax with respect to its input, given the gradient `grad` with respect to its
+    /// output. The Jacobian of softmax isn't diagonal, so unlike the other functions it takes the
+    /// incoming gradient
+    fn softmax_backward(&self, grad: &Self) -> Self;
+
+    /// Same as `map_mut` but returns a new value
+    fn map(&self, f: impl Fn(T) -> T) -> Self {
+        let mut mapped = self.clone();
+        mapped.map_mut(f);
+        mapped
+    }
+
+    /// Logistic function `1 / (1 + e^-x)`
+    fn sigmoid(&self) -> Self {
+        self.map(sigmoid)
+    }
+
+    /// Mutable logistic function
+    fn sigmoid_mut(&mut self) {
+        self.map_mut(sigmoid)
+    }
+
+    /// Derivative of the logistic function
+    fn sigmoid_derivative(&self) -> Self {
+        self.map(sigmoid_derivative)
+    }
+
+    /// Hyperbolic tangent
+    fn tanh(&self) -> Self {
+        self.map(T::tanh)
+    }
+
+    /// Mutable hyperbolic tangent
+    fn tanh_mut(&mut self) {
+        self.map_mut(T::tanh)
+    }
+
+    /// Derivative of the hyperbolic tangent
+    fn tanh_derivative(&self) -> Self {
+        self.map(|num| T::one() - num.tanh() * num.tanh())
+    }
+
+    /// Rectified linear unit `max(x, 0)`
+    fn relu(&self) -> Self {
+        self.leaky_relu(T::zero())
+    }
+
+    /// Mutable rectified linear unit
+    fn relu_mut(&mut self) {
+        self.leaky_relu_mut(T::zero())
+    }
+
+    /// Derivative of the rectified linear unit, taken to be zero at zero
+    fn relu_derivative(&self) -> Self {
+        self.leaky_relu_derivative(T::zero())
+    }
+
+    /// Leaky rectified linear unit, `x` for positive `x` and `alpha * x` otherwise
+    fn leaky_relu(&self, alpha: T) -> Self {
+        self.map(|num| leaky_relu(num, alpha))
+    }
+
+    /// Mutable leaky rectified linear unit
+    fn leaky_relu_mut(&mut self, alpha: T) {
+        self.map_mut(|num| leaky_relu(num, alpha))
+    }
+
+    /// Derivative of the leaky rectified linear unit, taken to be `alpha` at zero
+    fn leaky_relu_derivative(&self, alpha: T) -> Self {
+        self.map(|num| if num > T::zero() { T::one() } else { alpha })
+    }
+
+    /// Exponential linear unit, `x` for positive `x` and `alpha * (e^x - 1)` otherwise
+    fn elu(&self, alpha: T) -> Self {
+        self.map(|num| elu(num, alpha))
+    }
+
+    /// Mutable exponential linear unit
+    fn elu_mut(&mut self, alpha: T) {
+        self.map_mut(|num| elu(num, alpha))
+    }
+
+    /// Derivative of the exponential linear unit
+    fn elu_derivative(&self, alpha: T) -> Self {
+        self.map(|num| {
+            if num > T::zero() {
+                T::one()
+            } else {
+                alpha * num.exp()
+            }
+        })
+    }
+
+    /// Gaussian error linear unit, using the tanh approximation
+    /// `0.5x(1 + tanh(sqrt(2/π)(x + 0.044715x³)))`
+    fn gelu(&self) -> Self {
+        self.map(gelu)
+    }
+
+    /// Mutable Gaussian error linear unit
+    fn gelu_mut(&mut self) {
+        self.map_mut(gelu)
+    }
+
+    /// Derivative of the tanh approximation of the Gaussian error linear unit
+    fn gelu_derivative(&self) -> Self {
+        self.map(gelu_derivative)
+    }
+
+    /// Softplus `ln(1 + e^x)`, computed without overflowing for large `x`
+    fn softplus(&self) -> Self {
+        self.map(softplus)
+    }
+
+    /// Mutable softplus
+    fn softplus_mut(&mut self) {
+        self.map_mut(softplus)
+    }
+
+    /// Derivative of softplus, which is the logistic function
+    fn softplus_derivative(&self) -> Self {
+        self.sigmoid()
+    }
+
+    /// Swish `x * sigmoid(x)`, also known as SiLU
+    fn swish(&self) -> Self {
+        self.map(|num| num * sigmoid(num))
+    }
+
+    /// Mutable swish
+    fn swish_mut(&mut self) {
+        self.map_mut(|num| num * sigmoid(num))
+    }
+
+    /// Derivative of swish
+    fn swish_derivative(&self) -> Self {
+        self.map(|num| sigmoid(num) + num * sigmoid_derivative(num))
+    }
+
+    /// Softmax `e^x / sum(e^x)`, shifted by the largest item so no item overflows.
+    ///
+    /// Items of `-inf` get a probability of zero, which is how attention masks are applied. If every
+    /// item is `-inf` all probabilities are zero instead of `NaN`, and if any item is `inf` the
+    /// items of `inf` share all of the probability. A single `NaN` makes every probability `NaN`.
+    /// ```rust
+    /// # use sickmath::*;
+    /// let logits: Vector<f32, 3> = Vector::new([100.0, f32::NEG_INFINITY, 100.0]);
+    ///
+    /// assert_eq!(logits.softmax(), Vector::new([0.5, 0.0, 0.5]));
+    /// ```
+    fn softmax(&self) -> Self {
+        let mut softmax = self.clone();
+        softmax.softmax_mut();
+        softmax
+    }
+
+    /// Log-softmax `x - logsumexp(x)`, more accurate than taking the logarithm of `softmax`.
+    ///
+    /// Items of `-inf` stay `-inf`, also when every item is `-inf`. Like in `softmax`, items of
+    /// `inf` share all of the probability when there are any.
+    fn log_softmax(&self) -> Self {
+        let mut log_softmax = self.clone();
+        log_softmax.log_softmax_mut();
+        log_softmax
+    }
+}
+
+impl<T, const N: usize> Activation<T> for Vector<T, N>
+where
+    T: Default + Copy + Float,
+{
+    fn map_mut(&mut self, f: impl Fn(T) -> T) {
+        for num in self.iter_mut() {
+            *num = f(*num);
+        }
+    }
+
+    fn softmax_mut(&mut self) {
+        let normalizer = Normalizer::new(|| self.iter().copied());
+        self.map_mut(|num| normalizer.softmax(num));
+    }
+
+    fn log_softmax_mut(&mut self) {
+        let normalizer = Normalizer::new(|| self.iter().copied());
+        self.map_mut(|num| normalizer.log_softmax(num));
+    }
+
+    fn softmax_backward(&self, grad: &Self) -> Self {
+        let softmax = self.softmax();
+        let weighted = softmax
+            .iter()
+            .zip(grad.iter())
+            .fold(T::zero(), |acc, (prob, num)| acc + *prob * *num);
+
+        softmax
+            .iter()
+            .zip(grad.iter())
+            .map(|(prob, num)| *prob * (*num - weighted))
+            .collect()
+    }
+}
+
+/// Softmax and log-softmax are applied to every row on its own, see `softmax_columns` for the
+/// column-wise versions
+impl<T, const M: usize, const N: usize> Activation<T> for Matrix<T, M, N>
+where
+    T: Default + Copy + Float,
+{
+    fn map_mut(&mut self, f: impl Fn(T) -> T) {
+        for row in self.iter_mut() {
+            row.map_mut(&f);
+        }
+    }
+
+    fn softmax_mut(&mut self) {
+        for row in self.iter_mut() {
+            row.softmax_mut();
+        }
+    }
+
+    fn log_softmax_mut(&mut self) {
+        for row in self.iter_mut() {
+            row.log_softmax_mut();
+        }
+    }
+
+    fn softmax_backward(&self, grad: &Self) -> Self {
+        self.iter()
+            .zip(grad.iter())
+            .map(|(row, grad_row)| row.softmax_backward(grad_row))
+            .collect()
+    }
+}
+
+impl<T, const N: usize> Vector<T, N>
+where
+    T: Default + Copy + Float,
+{
+    /// Jacobian of softmax at `self`, `diag(s) - ssᵀ` with `s` the softmax of `self`
+    /// ```rust
+    /// # use sickmath::*;
+    /// let jacobian: Matrix<f64, 2, 2> = Vector::<f64, 2>::new([0.0, 0.0]).softmax_jacobian();
+    ///
+    /// assert_eq!(jacobian, Matrix::new([[0.25, -0.25], [-0.25, 0.25]]));
+    /// ```
+    pub fn softmax_jacobian(&self) -> Matrix<T, N, N> {
+        let softmax = self.softmax();
+
+        (0..N)
+            .map(|row| {
+                (0..N)
+                    .map(|col| {
+                        let diagonal = if row == col { softmax[row] } else { T::zero() };
+                        diagonal - softmax[row] * softmax[col]
+                    })
+                    .collect()
+            })
+            .collect()
+    }
+}
+
+/// Split on the sign so `exp` is only ever called on non-positive numbers
+fn sigmoid<T: Float>(num: T) -> T {
+    if num >= T::zero() {
+        T::one() / (T::one() + (-num).exp())
+    } else {
+        let exp = num.exp();
+        exp / (T::one() + exp)
+    }
+}
+
+fn sigmoid_derivative<T: Float>(num: T) -> T {
+    let sigmoid = sigmoid(num);
+    sigmoid * (T::one() - sigmoid)
+}
+
+fn leaky_relu<T: Float>(num: T, alpha: T) -> T {
+    if num > T::zero() {
+        num
+    } else {
+        alpha * num
+    }
+}
+
+fn elu<T: Float>(num: T, alpha: T) -> T {
+    if num > T::zero() {
+        num
+    } else {
+        alpha * num.exp_m1()
+    }
+}
+
+/// `sqrt(2/π)` and the cubic coefficient of the GELU approximation
+fn gelu_constants<T: Float>() -> (T, T) {
+    (
+        T::from(0.797_884_560_802_865_4).unwrap(),
+        T::from(0.044_715).unwrap(),
+    )
+}
+
+fn gelu<T: Float>(num: T) -> T {
+    let (scale, cubic) = gelu_constants::<T>();
+    let half = T::from(0.5).unwrap();
+
+    half * num * (T::one() + (scale * (num + cubic * num * num * num)).tanh())
+}
+
+fn gelu_derivative<T: Float>(num: T) -> T {
+    let (scale, cubic) = gelu_constants::<T>();
+    let half = T::from(0.5).unwrap();
+    let three = T::from(3.0).unwrap();
+
+    let tanh = (scale * (num + cubic * num * num * num)).tanh();
+    let inner_derivative = scale * (T::one() + three * cubic * num * num);
+
+    half * (T::one() + tanh) + half * num * (T::one() - tanh * tanh) * inner_derivative
+}
+
+/// `max(x, 0) + ln(1 + e^-|x|)`
+fn softplus<T: Float>(num: T) -> T {
+    num.max(T::zero()) + (-num.abs()).exp().ln_1p()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Activation;
+    use crate::{Matrix, Vector};
+
+    const INPUT: [f64; 7] = [-3.0, -1.0, -0.25, 0.0, 0.5, 1.5, 4.0];
+
+    /// Compares `derivative` against central differences of `f`
+    fn check_derivative(
+        f: impl Fn(&Vector<f64, 7>) -> Vector<f64, 7>,
+        derivative: impl Fn(&Vector<f64, 7>) -> Vector<f64, 7>,
+    ) {
+        let input: Vector<f64, 7> = Vector::new(INPUT);
+        let analytic = derivative(&input);
+
+        for idx in 0..7 {
+            // Skip the kink of the (leaky) ReLU
+            if INPUT[idx] == 0.0 {
+                continue;
+            }
+
+            let (mut plus, mut minus) = (input.clone(), input.clone());
+            plus[idx] += 1e-6;
+            minus[idx] -= 1e-6;
+
+            let numeric = (f(&plus)[idx] - f(&minus)[idx]) / 2e-6;
+            assert!(
+                (numeric - analytic[idx]).abs() < 1e-6,
+                "{}: {} != {}",
+                INPUT[idx],
+                numeric,
+                analytic[idx]
+            );
+        }
+    }
+
+    #[test]
+    fn derivatives_match_finite_differences() {
+        check_derivative(|x| x.sigmoid(), |x| x.sigmoid_derivative());
+        check_derivative(|x| x.tanh(), |x| x.tanh_derivative());
+        check_derivative(|x| x.relu(), |x| x.relu_derivative());
+        check_derivative(|x| x.leaky_relu(0.01), |x| x.leaky_relu_derivative(0.01));
+        check_derivative(|x| x.elu(1.5), |x| x.elu_derivative(1.5));
+        check_derivative(|x| x.gelu(), |x| x.gelu_derivative());
+        check_derivative(|x| x.softplus(), |x| x.softplus_derivative());
+        check_derivative(|x| x.swish(), |x| x.swish_derivative());
+    }
+
+    #[test]
+    fn known_values() {
+        let input: Vector<f64, 3> = Vector::new([-1.0, 0.0, 1.0]);
+
+        assert_eq!(input.sigmoid()[1], 0.5);
+        assert_eq!(input.elu(2.0)[0], 2.0 * ((-1.0_f64).exp() - 1.0));
+        assert!((input.gelu()[2] - 0.841_192).abs() < 1e-6);
+        assert!((input.softplus()[1] - 2.0_f64.ln()).abs() < 1e-15);
+        assert_eq!(input.swish()[2], 1.0 / (1.0 + (-1.0_f64).exp()));
+    }
+
+    #[test]
+    fn no_overflow_for_large_inputs() {
+        let input: Vector<f64, 2> = Vector::new([-1000.0, 1000.0]);
+
+        assert_eq!(input.sigmoid(), Vector::new([0.0, 1.0]));
+        assert_eq!(input.softplus(), Vector::new([0.0, 1000.0]));
+        assert_eq!(input.softmax(), Vector::new([0.0, 1.0]));
+    }
+
+    #[test]
+    fn mut_variants_match() {
+        let mut input: Vector<f32, 3> = Vector::new([-2.0, 0.5, 3.0]);
+        let expected = input.gelu();
+
+        input.gelu_mut();
+
+        assert_eq!(input, expected);
+    }
+
+    #[test]
+    fn softmax_backward_matches_jacobian() {
+        let input: Vector<f64, 3> = Vector::new([0.2, -1.0, 2.5]);
+        let grad: Vector<f64, 3> = Vector::new([1.0, 0.5, -2.0]);
+
+        let backward = input.softmax_backward(&grad);
+        let jacobian = input.softmax_jacobian();
+
+        for row in 0..3 {
+            let expected: f64 = (0..3).map(|col| jacobian[col][row] * grad[col]).sum();
+            assert!((backward[row] - expected).abs() < 1e-15);
+        }
+    }
+
+    #[test]
+    fn matrix_softmax_is_row_wise() {
+        let mut matrix: Matrix<f64, 2, 3> = Matrix::new([[1.0, 2.0, 3.0], [0.0, 0.0, 0.0]]);
+
+        matrix.softmax_mut();
+
+        assert_eq!(matrix[0], Vector::<f64, 3>::new([1.0, 2.0, 3.0]).softmax());
+        assert_eq!(matrix[1], Vector::new([1.0 / 3.0; 3]));
+        assert_eq!(
+            Matrix::<f64, 1, 2>::new([[-1.0, 1.0]]).relu(),
+            Matrix::new([[0.0, 1.0]])
+        );
+    }
+}
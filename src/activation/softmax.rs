@@ -0,0 +1,252 @@
+use num::Float;
+
+use crate::{matrix::Matrix, vector::Vector};
+
+impl<T, const N: usize> Vector<T, N>
+where
+    T: Default + Copy + Float,
+{
+    /// `ln(sum(e^x))`, shifted by the largest item so it neither overflows nor underflows.
+    ///
+    /// Returns `NaN` if any item is `NaN`, otherwise `-inf` if every item is `-inf` and `inf` if
+    /// any item is `inf`.
+    /// ```rust
+    /// # use sickmath::*;
+    /// let logits: Vector<f32, 2> = Vector::new([1000.0, 1000.0]);
+    ///
+    /// assert_eq!(logits.logsumexp(), 1000.0 + 2.0_f32.ln());
+    /// ```
+    pub fn logsumexp(&self) -> T {
+        Normalizer::new(|| self.iter().copied()).logsumexp()
+    }
+}
+
+impl<T, const M: usize, const N: usize> Matrix<T, M, N>
+where
+    T: Default + Copy + Float,
+{
+    /// `logsumexp` of every row
+    pub fn logsumexp_rows(&self) -> Vector<T, M> {
+        self.iter().map(|row| row.logsumexp()).collect()
+    }
+
+    /// `logsumexp` of every column
+    pub fn logsumexp_columns(&self) -> Vector<T, N> {
+        (0..N)
+            .map(|col| Normalizer::new(|| self.iter().map(|row| row[col])).logsumexp())
+            .collect()
+    }
+
+    /// Softmax of every column, the row-wise softmax is `Activation::softmax`
+    /// ```rust
+    /// # use sickmath::*;
+    /// let scores: Matrix<f64, 2, 2> = Matrix::new([[0.0, 5.0], [0.0, f64::NEG_INFINITY]]);
+    ///
+    /// assert_eq!(scores.softmax_columns(), Matrix::new([[0.5, 1.0], [0.5, 0.0]]));
+    /// ```
+    pub fn softmax_columns(&self) -> Matrix<T, M, N> {
+        let mut softmax = self.clone();
+        softmax.softmax_columns_mut();
+        softmax
+    }
+
+    /// Mutable column-wise softmax
+    pub fn softmax_columns_mut(&mut self) {
+        for col in 0..N {
+            let normalizer = Normalizer::new(|| self.iter().map(|row| row[col]));
+
+            for row in self.iter_mut() {
+                row[col] = normalizer.softmax(row[col]);
+            }
+        }
+    }
+
+    /// Log-softmax of every column
+    pub fn log_softmax_columns(&self) -> Matrix<T, M, N> {
+        let mut log_softmax = self.clone();
+        log_softmax.log_softmax_columns_mut();
+        log_softmax
+    }
+
+    /// Mutable column-wise log-softmax
+    pub fn log_softmax_columns_mut(&mut self) {
+        for col in 0..N {
+            let normalizer = Normalizer::new(|| self.iter().map(|row| row[col]));
+
+            for row in self.iter_mut() {
+                row[col] = normalizer.log_softmax(row[col]);
+            }
+        }
+    }
+}
+
+/// What softmax normalizes by, found by visiting the items twice
+pub(super) enum Normalizer<T> {
+    /// An item is `NaN`, which makes every result `NaN`
+    Undefined,
+    /// Every item is `-inf`, so there is no probability to hand out
+    Masked,
+    /// `count` items are `inf` and share all of the probability
+    Infinite { count: T },
+    /// The largest item and `sum(e^(x - max))`
+    Finite { max: T, sum: T },
+}
+
+impl<T> Normalizer<T>
+where
+    T: Float,
+{
+    /// Takes a function returning the items since they're visited more than once
+    pub(super) fn new<I: Iterator<Item = T>>(items: impl Fn() -> I) -> Self {
+        // `max` skips `NaN`, so it's looked for separately
+        let (max, nan) = items().fold((T::neg_infinity(), false), |(max, nan), num| {
+            (max.max(num), nan || num.is_nan())
+        });
+
+        if nan {
+            Normalizer::Undefined
+        } else if max == T::neg_infinity() {
+            Normalizer::Masked
+        } else if max == T::infinity() {
+            Normalizer::Infinite {
+                count: items()
+                    .filter(|num| *num == T::infinity())
+                    .fold(T::zero(), |acc, _| acc + T::one()),
+            }
+        } else {
+            Normalizer::Finite {
+                max,
+                sum: items()
+                    .map(|num| (num - max).exp())
+                    .fold(T::zero(), |acc, num| acc + num),
+            }
+        }
+    }
+
+    pub(super) fn logsumexp(&self) -> T {
+        match self {
+            Normalizer::Undefined => T::nan(),
+            Normalizer::Masked => T::neg_infinity(),
+            Normalizer::Infinite { .. } => T::infinity(),
+            Normalizer::Finite { max, sum } => *max + sum.ln(),
+        }
+    }
+
+    pub(super) fn softmax(&self, num: T) -> T {
+        match self {
+            Normalizer::Undefined => T::nan(),
+            Normalizer::Masked => T::zero(),
+            Normalizer::Infinite { count } if num == T::infinity() => count.recip(),
+            Normalizer::Infinite { .. } => T::zero(),
+            Normalizer::Finite { max, sum } => (num - *max).exp() / *sum,
+        }
+    }
+
+    pub(super) fn log_softmax(&self, num: T) -> T {
+        match self {
+            Normalizer::Undefined => T::nan(),
+            Normalizer::Infinite { count } if num == T::infinity() => -count.ln(),
+            Normalizer::Masked | Normalizer::Infinite { .. } => T::neg_infinity(),
+            Normalizer::Finite { .. } => num - self.logsumexp(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Activation, Matrix, Vector};
+
+    #[test]
+    fn large_logits_dont_overflow() {
+        let logits: Vector<f32, 3> = Vector::new([89.0, 90.0, 91.0]);
+        let shifted: Vector<f32, 3> = Vector::new([-2.0, -1.0, 0.0]);
+
+        assert_eq!(logits.softmax(), shifted.softmax());
+        assert!((logits.logsumexp() - 91.0 - shifted.logsumexp()).abs() < 1e-5);
+
+        let log_softmax = logits.log_softmax();
+        for (num, expected) in log_softmax.iter().zip(shifted.log_softmax().iter()) {
+            assert!((num - expected).abs() < 1e-5);
+        }
+    }
+
+    #[test]
+    fn log_softmax_is_log_of_softmax() {
+        let logits: Vector<f64, 4> = Vector::new([0.3, -2.0, 1.7, 0.0]);
+
+        for (log_prob, prob) in logits.log_softmax().iter().zip(logits.softmax().iter()) {
+            assert!((log_prob - prob.ln()).abs() < 1e-15);
+        }
+    }
+
+    #[test]
+    fn masked_entries() {
+        let inf = f64::INFINITY;
+        let partly_masked: Vector<f64, 3> = Vector::new([1.0, -inf, 1.0]);
+        let masked: Vector<f64, 3> = Vector::new([-inf; 3]);
+
+        assert_eq!(partly_masked.log_softmax()[1], -inf);
+        assert_eq!(partly_masked.logsumexp(), 1.0 + 2.0_f64.ln());
+        assert_eq!(masked.softmax(), Vector::new([0.0; 3]));
+        assert_eq!(masked.log_softmax(), masked);
+        assert_eq!(masked.logsumexp(), -inf);
+        assert_eq!(Vector::<f64, 2>::new([0.0, inf]).logsumexp(), inf);
+    }
+
+    #[test]
+    fn infinite_entries_take_all_probability() {
+        let inf = f64::INFINITY;
+        let one_infinite: Vector<f64, 2> = Vector::new([0.0, inf]);
+        let two_infinite: Vector<f64, 4> = Vector::new([inf, -inf, 3.0, inf]);
+
+        assert_eq!(one_infinite.softmax(), Vector::new([0.0, 1.0]));
+        assert_eq!(one_infinite.log_softmax(), Vector::new([-inf, 0.0]));
+        assert_eq!(two_infinite.softmax(), Vector::new([0.5, 0.0, 0.0, 0.5]));
+        assert_eq!(
+            two_infinite.log_softmax(),
+            Vector::new([-2.0_f64.ln(), -inf, -inf, -2.0_f64.ln()])
+        );
+
+        let matrix: Matrix<f64, 2, 2> = Matrix::new([[inf, 1.0], [2.0, 1.0]]);
+        assert_eq!(matrix.softmax_columns()[0][0], 1.0);
+        assert_eq!(matrix.softmax_columns()[1][0], 0.0);
+        assert_eq!(matrix.log_softmax_columns()[1][0], -inf);
+        assert_eq!(matrix.softmax()[0], Vector::new([1.0, 0.0]));
+    }
+
+    #[test]
+    fn nan_makes_everything_nan() {
+        let inf = f64::INFINITY;
+        let vectors: [Vector<f64, 3>; 3] = [
+            Vector::new([f64::NAN, 1.0, 2.0]),
+            Vector::new([f64::NAN, -inf, -inf]),
+            Vector::new([f64::NAN, inf, 0.0]),
+        ];
+
+        for vector in vectors.iter() {
+            assert!(vector.logsumexp().is_nan());
+            assert!(vector.softmax().iter().all(|num| num.is_nan()));
+            assert!(vector.log_softmax().iter().all(|num| num.is_nan()));
+        }
+    }
+
+    #[test]
+    fn row_and_column_wise() {
+        let inf = f64::INFINITY;
+        let matrix: Matrix<f64, 2, 3> = Matrix::new([[1.0, -inf, 0.5], [2.0, -inf, -0.5]]);
+
+        let rows = matrix.log_softmax();
+        let columns = matrix.log_softmax_columns();
+        let column_0: Vector<f64, 2> = Vector::new([1.0, 2.0]);
+
+        assert_eq!(rows[0], matrix[0].log_softmax());
+        assert_eq!(matrix.logsumexp_rows()[1], matrix[1].logsumexp());
+        assert_eq!(columns[0][0], column_0.log_softmax()[0]);
+        assert_eq!(columns[1][1], -inf);
+        assert_eq!(matrix.logsumexp_columns()[1], -inf);
+
+        let softmax = matrix.softmax_columns();
+        assert_eq!(softmax[0][1], 0.0);
+        assert!((softmax[0][2] + softmax[1][2] - 1.0).abs() < 1e-15);
+    }
+}
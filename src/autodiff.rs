@@ -0,0 +1,457 @@
+pub mod check;
+pub mod ops;
+
+pub use check::check_gradient;
+
+use std::{cell::RefCell, fmt::Debug, marker::PhantomData, ops::AddAssign};
+
+use num::Float;
+
+use crate::{matrix::Matrix, vector::Vector};
+
+/// Values that can be recorded on a `Tape`: `Vector`, `Matrix` and the scalars `f32` and `f64`.
+///
+/// On the tape every value is kept as a flat, row-major `Vec`.
+pub trait Differentiable<T>: Sized {
+    fn to_flat(&self) -> Vec<T>;
+
+    /// Rebuilds the value from `to_flat`. Panics if `flat` has the wrong length
+    fn from_flat(flat: &[T]) -> Self;
+}
+
+impl<T, const N: usize> Differentiable<T> for Vector<T, N>
+where
+    T: Default + Copy,
+{
+    fn to_flat(&self) -> Vec<T> {
+        self.iter().copied().collect()
+    }
+
+    fn from_flat(flat: &[T]) -> Self {
+        assert_eq!(flat.len(), N);
+        flat.iter().copied().collect()
+    }
+}
+
+impl<T, const M: usize, const N: usize> Differentiable<T> for Matrix<T, M, N>
+where
+    T: Default + Copy,
+{
+    fn to_flat(&self) -> Vec<T> {
+        self.to_row_major()
+    }
+
+    fn from_flat(flat: &[T]) -> Self {
+        Matrix::from_row_major(flat)
+    }
+}
+
+impl Differentiable<f32> for f32 {
+    fn to_flat(&self) -> Vec<f32> {
+        vec![*self]
+    }
+
+    fn from_flat(flat: &[f32]) -> Self {
+        assert_eq!(flat.len(), 1);
+        flat[0]
+    }
+}
+
+impl Differentiable<f64> for f64 {
+    fn to_flat(&self) -> Vec<f64> {
+        vec![*self]
+    }
+
+    fn from_flat(flat: &[f64]) -> Self {
+        assert_eq!(flat.len(), 1);
+        flat[0]
+    }
+}
+
+/// How a node on the tape was computed from earlier nodes
+#[derive(Debug, Clone)]
+enum Op<T> {
+    Leaf,
+    Add(usize, usize),
+    Sub(usize, usize),
+    Entrywise(usize, usize),
+    Scale(usize, T),
+    Sum(usize),
+    Dot(usize, usize),
+    /// `lhs` is `rows` x `inner` and `rhs` is `inner` x `cols`, a vector is a single column
+    MatMul {
+        lhs: usize,
+        rhs: usize,
+        rows: usize,
+        inner: usize,
+        cols: usize,
+    },
+    Transpose {
+        input: usize,
+        rows: usize,
+        cols: usize,
+    },
+    /// An elementwise function, with its derivative at every item of `input`
+    Map {
+        input: usize,
+        derivative: Vec<T>,
+    },
+}
+
+#[derive(Debug, Clone)]
+struct Node<T> {
+    value: Vec<T>,
+    op: Op<T>,
+}
+
+/// Records operations on `Var`s so their gradients can be computed in reverse.
+///
+/// Create the inputs with `var`, compute a scalar from them and call `backward` on it. The
+/// gradient of every input then has the same shape as the input itself.
+/// ```rust
+/// # use sickmath::*;
+/// let tape: Tape<f64> = Tape::new();
+/// let weights = tape.var(Matrix::<f64, 2, 3>::new([[1.0, 2.0, 3.0], [4.0, 5.0, 6.0]]));
+/// let input = tape.var(Vector::<f64, 3>::new([1.0, 0.0, -1.0]));
+///
+/// // loss = sum(tanh(Wx))
+/// let loss = weights.mul_vector(&input).tanh().sum();
+/// let gradients = loss.backward();
+///
+/// let weight_gradient: Matrix<f64, 2, 3> = gradients.wrt(&weights);
+/// let sech_squared = 1.0 - (-2.0_f64).tanh().powi(2);
+/// assert!((weight_gradient[0][0] - sech_squared).abs() < 1e-12);
+/// ```
+#[derive(Debug, Default)]
+pub struct Tape<T> {
+    nodes: RefCell<Vec<Node<T>>>,
+}
+
+impl<T> Tape<T> {
+    pub fn new() -> Self {
+        Tape {
+            nodes: RefCell::new(Vec::new()),
+        }
+    }
+
+    /// Number of recorded values
+    pub fn len(&self) -> usize {
+        self.nodes.borrow().len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.nodes.borrow().is_empty()
+    }
+
+    /// Records an input
+    pub fn var<V: Differentiable<T>>(&self, value: V) -> Var<'_, T, V> {
+        self.push(value.to_flat(), Op::Leaf)
+    }
+
+    fn push<V>(&self, value: Vec<T>, op: Op<T>) -> Var<'_, T, V> {
+        let mut nodes = self.nodes.borrow_mut();
+        nodes.push(Node { value, op });
+
+        Var {
+            tape: self,
+            index: nodes.len() - 1,
+            marker: PhantomData,
+        }
+    }
+
+    fn value(&self, index: usize) -> Vec<T>
+    where
+        T: Clone,
+    {
+        self.nodes.borrow()[index].value.clone()
+    }
+}
+
+/// A value of type `V` recorded on a `Tape`.
+///
+/// `Var` is a cheap handle and can be copied freely. Operations on it record a new node and
+/// return a new `Var`, the value is available through `value`.
+pub struct Var<'t, T, V> {
+    tape: &'t Tape<T>,
+    index: usize,
+    marker: PhantomData<V>,
+}
+
+impl<'t, T, V> Clone for Var<'t, T, V> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<'t, T, V> Copy for Var<'t, T, V> {}
+
+impl<'t, T, V> Debug for Var<'t, T, V> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Var").field("index", &self.index).finish()
+    }
+}
+
+impl<'t, T, V> Var<'t, T, V> {
+    /// The tape this value is recorded on, useful to record constants next to it
+    pub fn tape(&self) -> &'t Tape<T> {
+        self.tape
+    }
+}
+
+impl<'t, T, V> Var<'t, T, V>
+where
+    T: Clone,
+    V: Differentiable<T>,
+{
+    pub fn value(&self) -> V {
+        V::from_flat(&self.tape.value(self.index))
+    }
+}
+
+/// Gradients of a scalar with respect to every value on a tape
+#[derive(Debug, Clone)]
+pub struct Gradients<T> {
+    grads: Vec<Vec<T>>,
+}
+
+impl<T> Gradients<T> {
+    /// The gradient with respect to `var`, which has the same shape as `var`
+    pub fn wrt<V: Differentiable<T>>(&self, var: &Var<'_, T, V>) -> V {
+        V::from_flat(&self.grads[var.index])
+    }
+}
+
+impl<'t, T> Var<'t, T, T>
+where
+    T: Float + AddAssign + Default + Debug,
+{
+    /// Propagates the gradient of this scalar back through every recorded operation
+    pub fn backward(&self) -> Gradients<T> {
+        let nodes = self.tape.nodes.borrow();
+        let mut grads: Vec<Vec<T>> = nodes
+            .iter()
+            .map(|node| vec![T::zero(); node.value.len()])
+            .collect();
+        grads[self.index][0] = T::one();
+
+        for idx in (0..=self.index).rev() {
+            let grad = std::mem::take(&mut grads[idx]);
+
+            match &nodes[idx].op {
+                Op::Leaf => {}
+                Op::Add(lhs, rhs) => {
+                    accumulate(&mut grads[*lhs], &grad, |_, num| num);
+                    accumulate(&mut grads[*rhs], &grad, |_, num| num);
+                }
+                Op::Sub(lhs, rhs) => {
+                    accumulate(&mut grads[*lhs], &grad, |_, num| num);
+                    accumulate(&mut grads[*rhs], &grad, |_, num| -num);
+                }
+                Op::Entrywise(lhs, rhs) => {
+                    let (lhs_value, rhs_value) = (&nodes[*lhs].value, &nodes[*rhs].value);
+                    accumulate(&mut grads[*lhs], &grad, |i, num| num * rhs_value[i]);
+                    accumulate(&mut grads[*rhs], &grad, |i, num| num * lhs_value[i]);
+                }
+                Op::Scale(input, scalar) => {
+                    accumulate(&mut grads[*input], &grad, |_, num| num * *scalar);
+                }
+                Op::Sum(input) => {
+                    let len = grads[*input].len();
+                    accumulate(&mut grads[*input], &vec![grad[0]; len], |_, num| num);
+                }
+                Op::Dot(lhs, rhs) => {
+                    let (lhs_value, rhs_value) = (&nodes[*lhs].value, &nodes[*rhs].value);
+                    accumulate(&mut grads[*lhs], rhs_value, |_, num| num * grad[0]);
+                    accumulate(&mut grads[*rhs], lhs_value, |_, num| num * grad[0]);
+                }
+                Op::MatMul {
+                    lhs,
+                    rhs,
+                    rows,
+                    inner,
+                    cols,
+                } => {
+                    let (lhs_value, rhs_value) = (&nodes[*lhs].value, &nodes[*rhs].value);
+
+                    // dA = dC Bᵀ and dB = Aᵀ dC
+                    for row in 0..*rows {
+                        for idx in 0..*inner {
+                            let mut acc = T::zero();
+                            for col in 0..*cols {
+                                acc += grad[row * cols + col] * rhs_value[idx * cols + col];
+                            }
+                            grads[*lhs][row * inner + idx] += acc;
+                        }
+                    }
+                    for idx in 0..*inner {
+                        for col in 0..*cols {
+                            let mut acc = T::zero();
+                            for row in 0..*rows {
+                                acc += lhs_value[row * inner + idx] * grad[row * cols + col];
+                            }
+                            grads[*rhs][idx * cols + col] += acc;
+                        }
+                    }
+                }
+                Op::Transpose { input, rows, cols } => {
+                    for row in 0..*rows {
+                        for col in 0..*cols {
+                            grads[*input][row * cols + col] += grad[col * rows + row];
+                        }
+                    }
+                }
+                Op::Map { input, derivative } => {
+                    accumulate(&mut grads[*input], &grad, |i, num| num * derivative[i]);
+                }
+            }
+
+            grads[idx] = grad;
+        }
+
+        Gradients { grads }
+    }
+}
+
+/// `target[i] += contribution(i, source[i])`
+fn accumulate<T>(target: &mut [T], source: &[T], contribution: impl Fn(usize, T) -> T)
+where
+    T: Copy + AddAssign,
+{
+    for (idx, (num, source_num)) in target.iter_mut().zip(source.iter()).enumerate() {
+        *num += contribution(idx, *source_num);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{check_gradient, Matrix, Tape, Vector};
+
+    #[test]
+    fn dot_gradient() {
+        let tape: Tape<f64> = Tape::new();
+        let a = tape.var(Vector::<f64, 3>::new([1.0, 2.0, 3.0]));
+        let b = tape.var(Vector::<f64, 3>::new([4.0, 5.0, 6.0]));
+
+        let dot = a.dot(&b);
+        let gradients = dot.backward();
+
+        assert_eq!(dot.value(), 32.0);
+        assert_eq!(gradients.wrt(&a), Vector::new([4.0, 5.0, 6.0]));
+        assert_eq!(gradients.wrt(&b), Vector::new([1.0, 2.0, 3.0]));
+    }
+
+    #[test]
+    fn reused_var_accumulates() {
+        let tape: Tape<f64> = Tape::new();
+        let x = tape.var(Vector::<f64, 2>::new([3.0, -1.0]));
+
+        // x·x + sum(x ∘ x) = 2|x|², so the gradient is 4x
+        let y = x.dot(&x).add(&x.entrywise(&x).sum());
+
+        assert_eq!(y.backward().wrt(&x), Vector::new([12.0, -4.0]));
+    }
+
+    #[test]
+    fn unused_var_has_zero_gradient() {
+        let tape: Tape<f64> = Tape::new();
+        let x = tape.var(Vector::<f64, 2>::new([1.0, 2.0]));
+        let unused = tape.var(Matrix::<f64, 2, 2>::new([[1.0, 2.0], [3.0, 4.0]]));
+
+        let gradients = x.exp().sum().backward();
+
+        assert_eq!(gradients.wrt(&unused), Matrix::default());
+        assert_eq!(tape.len(), 4);
+    }
+
+    #[test]
+    #[should_panic]
+    fn vars_from_different_tapes() {
+        let (first, second): (Tape<f64>, Tape<f64>) = (Tape::new(), Tape::new());
+        let x = first.var(Vector::<f64, 2>::new([1.0, 2.0]));
+        let y = second.var(Vector::<f64, 2>::new([3.0, 4.0]));
+
+        x.dot(&y);
+    }
+
+    #[test]
+    fn matrix_mult_gradient() {
+        let tape: Tape<f64> = Tape::new();
+        let a = tape.var(Matrix::<f64, 2, 3>::new([[1.0, 2.0, 3.0], [4.0, 5.0, 6.0]]));
+        let b = tape.var(Matrix::<f64, 3, 2>::new([
+            [1.0, 0.0],
+            [0.0, 1.0],
+            [2.0, -1.0],
+        ]));
+
+        let gradients = a.mult(&b).sum().backward();
+
+        // d sum(AB) / dA = 1 Bᵀ and d sum(AB) / dB = Aᵀ 1
+        assert_eq!(
+            gradients.wrt(&a),
+            Matrix::new([[1.0, 1.0, 1.0], [1.0, 1.0, 1.0]])
+        );
+        assert_eq!(
+            gradients.wrt(&b),
+            Matrix::new([[5.0, 5.0], [7.0, 7.0], [9.0, 9.0]])
+        );
+    }
+
+    #[test]
+    fn check_vector_ops() {
+        let input: Vector<f64, 4> = Vector::new([0.3, -1.2, 2.0, 0.7]);
+
+        let error = check_gradient(
+            |x| {
+                let y = x.tanh().add_vector(&x.sigmoid()).entrywise(&x.exp());
+                y.sub_vector(&x.square().scalar(0.5)).dot(&x)
+            },
+            &input,
+            1e-6,
+        );
+
+        assert!(error < 1e-6, "{}", error);
+    }
+
+    #[test]
+    fn check_matrix_ops() {
+        let input: Matrix<f64, 3, 2> = Matrix::new([[0.5, -0.2], [1.5, 0.1], [-0.7, 0.9]]);
+
+        let error = check_gradient(
+            |x| {
+                let gram = x.transpose().mult(&x);
+                gram.add(&gram.entrywise(&gram))
+                    .subtract(&gram.scalar(0.1))
+                    .ln()
+                    .sum()
+            },
+            &input,
+            1e-6,
+        );
+
+        assert!(error < 1e-6, "{}", error);
+    }
+
+    #[test]
+    fn check_linear_layer() {
+        let weights: Matrix<f64, 2, 3> = Matrix::new([[0.1, -0.3, 0.2], [0.4, 0.0, -0.5]]);
+        let target: Vector<f64, 2> = Vector::new([1.0, 0.0]);
+        let input: Vector<f64, 3> = Vector::new([1.0, 2.0, -1.0]);
+
+        let error = check_gradient(
+            |x| {
+                let weights = x.tape().var(weights.clone());
+                let target = x.tape().var(target.clone());
+                weights
+                    .mul_vector(&x)
+                    .relu()
+                    .sub_vector(&target)
+                    .square()
+                    .sum()
+            },
+            &input,
+            1e-6,
+        );
+
+        assert!(error < 1e-6, "{}", error);
+    }
+}
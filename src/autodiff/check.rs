@@ -0,0 +1,54 @@
+use std::{fmt::Debug, ops::AddAssign};
+
+use num::Float;
+
+use super::{Differentiable, Tape, Var};
+
+/// Compares the gradient from `backward` against central differences and returns the largest
+/// absolute difference over all items of `input`.
+///
+/// `f` is evaluated `2 * len + 1` times on fresh tapes, with every item of `input` moved by
+/// `epsilon` in both directions.
+/// ```rust
+/// # use sickmath::*;
+/// let input: Matrix<f64, 2, 2> = Matrix::new([[0.5, -1.0], [2.0, 0.25]]);
+///
+/// let error = check_gradient(|x| x.mult(&x.transpose()).sigmoid().sum(), &input, 1e-6);
+/// assert!(error < 1e-8);
+/// ```
+pub fn check_gradient<T, V>(
+    f: impl for<'t> Fn(Var<'t, T, V>) -> Var<'t, T, T>,
+    input: &V,
+    epsilon: T,
+) -> T
+where
+    T: Float + AddAssign + Default + Debug + Differentiable<T>,
+    V: Differentiable<T>,
+{
+    let tape = Tape::new();
+    let var = tape.var(V::from_flat(&input.to_flat()));
+    let analytic = f(var).backward().wrt(&var).to_flat();
+
+    let evaluate = |flat: &[T]| {
+        let tape = Tape::new();
+        f(tape.var(V::from_flat(flat))).value()
+    };
+
+    let mut flat = input.to_flat();
+    let mut largest_error = T::zero();
+
+    for (idx, analytic_num) in analytic.into_iter().enumerate() {
+        let original = flat[idx];
+
+        flat[idx] = original + epsilon;
+        let plus = evaluate(&flat);
+        flat[idx] = original - epsilon;
+        let minus = evaluate(&flat);
+        flat[idx] = original;
+
+        let numeric = (plus - minus) / (epsilon + epsilon);
+        largest_error = largest_error.max((numeric - analytic_num).abs());
+    }
+
+    largest_error
+}
@@ -0,0 +1,264 @@
+use std::{fmt::Debug, ops::AddAssign, ptr};
+
+use num::Float;
+
+use super::{Differentiable, Op, Var};
+use crate::{matrix::gemm::gemm, matrix::Matrix, vector::Vector};
+
+impl<'t, T, V> Var<'t, T, V>
+where
+    T: Float + AddAssign + Default + Debug,
+    V: Differentiable<T>,
+{
+    /// Records `f` applied to every item, `derivative` has to be the derivative of `f`
+    /// ```rust
+    /// # use sickmath::*;
+    /// let tape: Tape<f64> = Tape::new();
+    /// let x = tape.var(Vector::<f64, 2>::new([1.0, 2.0]));
+    ///
+    /// let cubed = x.map(|num| num.powi(3), |num| 3.0 * num * num);
+    /// let gradient: Vector<f64, 2> = cubed.sum().backward().wrt(&x);
+    /// assert_eq!(gradient, Vector::new([3.0, 12.0]));
+    /// ```
+    pub fn map(&self, f: impl Fn(T) -> T, derivative: impl Fn(T) -> T) -> Var<'t, T, V> {
+        let input = self.tape.value(self.index);
+        let value = input.iter().map(|num| f(*num)).collect();
+        let derivative = input.iter().map(|num| derivative(*num)).collect();
+
+        self.tape.push(
+            value,
+            Op::Map {
+                input: self.index,
+                derivative,
+            },
+        )
+    }
+
+    pub fn exp(&self) -> Var<'t, T, V> {
+        self.map(T::exp, T::exp)
+    }
+
+    pub fn ln(&self) -> Var<'t, T, V> {
+        self.map(T::ln, T::recip)
+    }
+
+    pub fn square(&self) -> Var<'t, T, V> {
+        self.map(|num| num * num, |num| num + num)
+    }
+
+    pub fn tanh(&self) -> Var<'t, T, V> {
+        self.map(T::tanh, |num| T::one() - num.tanh() * num.tanh())
+    }
+
+    pub fn sigmoid(&self) -> Var<'t, T, V> {
+        let sigmoid = |num: T| T::one() / (T::one() + (-num).exp());
+
+        self.map(sigmoid, move |num| sigmoid(num) * (T::one() - sigmoid(num)))
+    }
+
+    /// The derivative at zero is taken to be zero
+    pub fn relu(&self) -> Var<'t, T, V> {
+        self.map(
+            |num| num.max(T::zero()),
+            |num| if num > T::zero() { T::one() } else { T::zero() },
+        )
+    }
+
+    /// Multiplies every item with `scalar`
+    pub fn scalar(&self, scalar: T) -> Var<'t, T, V> {
+        let value = self
+            .tape
+            .value(self.index)
+            .into_iter()
+            .map(|num| num * scalar)
+            .collect();
+
+        self.tape.push(value, Op::Scale(self.index, scalar))
+    }
+
+    /// Sum of all items
+    pub fn sum(&self) -> Var<'t, T, T> {
+        let value = self
+            .tape
+            .value(self.index)
+            .into_iter()
+            .fold(T::zero(), |acc, num| acc + num);
+
+        self.tape.push(vec![value], Op::Sum(self.index))
+    }
+
+    /// Panics if `other` is on another tape, where its index points at an unrelated node
+    fn assert_same_tape<W>(&self, other: &Var<'t, T, W>) {
+        assert!(
+            ptr::eq(self.tape, other.tape),
+            "Vars must be recorded on the same tape"
+        );
+    }
+
+    fn zip_with(&self, other: &Var<'t, T, V>, f: impl Fn(T, T) -> T, op: Op<T>) -> Var<'t, T, V> {
+        self.assert_same_tape(other);
+        let value = self
+            .tape
+            .value(self.index)
+            .into_iter()
+            .zip(self.tape.value(other.index))
+            .map(|(lhs, rhs)| f(lhs, rhs))
+            .collect();
+
+        self.tape.push(value, op)
+    }
+
+    fn add_var(&self, other: &Var<'t, T, V>) -> Var<'t, T, V> {
+        self.zip_with(
+            other,
+            |lhs, rhs| lhs + rhs,
+            Op::Add(self.index, other.index),
+        )
+    }
+
+    fn sub_var(&self, other: &Var<'t, T, V>) -> Var<'t, T, V> {
+        self.zip_with(
+            other,
+            |lhs, rhs| lhs - rhs,
+            Op::Sub(self.index, other.index),
+        )
+    }
+
+    fn entrywise_var(&self, other: &Var<'t, T, V>) -> Var<'t, T, V> {
+        self.zip_with(
+            other,
+            |lhs, rhs| lhs * rhs,
+            Op::Entrywise(self.index, other.index),
+        )
+    }
+
+    /// Records the product of a `rows` x `inner` and an `inner` x `cols` value
+    fn matmul<W, O>(
+        &self,
+        other: &Var<'t, T, W>,
+        rows: usize,
+        inner: usize,
+        cols: usize,
+    ) -> Var<'t, T, O> {
+        self.assert_same_tape(other);
+        let (lhs, rhs) = (self.tape.value(self.index), self.tape.value(other.index));
+        let mut value = vec![T::zero(); rows * cols];
+        gemm(
+            rows,
+            cols,
+            inner,
+            |row| &lhs[row * inner..(row + 1) * inner],
+            |row| &rhs[row * cols..(row + 1) * cols],
+            &mut value,
+        );
+
+        self.tape.push(
+            value,
+            Op::MatMul {
+                lhs: self.index,
+                rhs: other.index,
+                rows,
+                inner,
+                cols,
+            },
+        )
+    }
+}
+
+impl<'t, T> Var<'t, T, T>
+where
+    T: Float + AddAssign + Default + Debug + Differentiable<T>,
+{
+    pub fn add(&self, other: &Var<'t, T, T>) -> Var<'t, T, T> {
+        self.add_var(other)
+    }
+
+    pub fn sub(&self, other: &Var<'t, T, T>) -> Var<'t, T, T> {
+        self.sub_var(other)
+    }
+
+    pub fn mul(&self, other: &Var<'t, T, T>) -> Var<'t, T, T> {
+        self.entrywise_var(other)
+    }
+}
+
+impl<'t, T, const N: usize> Var<'t, T, Vector<T, N>>
+where
+    T: Float + AddAssign + Default + Debug,
+{
+    pub fn add_vector(&self, other: &Var<'t, T, Vector<T, N>>) -> Var<'t, T, Vector<T, N>> {
+        self.add_var(other)
+    }
+
+    pub fn sub_vector(&self, other: &Var<'t, T, Vector<T, N>>) -> Var<'t, T, Vector<T, N>> {
+        self.sub_var(other)
+    }
+
+    pub fn entrywise(&self, other: &Var<'t, T, Vector<T, N>>) -> Var<'t, T, Vector<T, N>> {
+        self.entrywise_var(other)
+    }
+
+    pub fn dot(&self, other: &Var<'t, T, Vector<T, N>>) -> Var<'t, T, T> {
+        self.assert_same_tape(other);
+        let value = self
+            .tape
+            .value(self.index)
+            .into_iter()
+            .zip(self.tape.value(other.index))
+            .fold(T::zero(), |acc, (lhs, rhs)| acc + lhs * rhs);
+
+        self.tape
+            .push(vec![value], Op::Dot(self.index, other.index))
+    }
+}
+
+impl<'t, T, const M: usize, const N: usize> Var<'t, T, Matrix<T, M, N>>
+where
+    T: Float + AddAssign + Default + Debug,
+{
+    pub fn add(&self, other: &Var<'t, T, Matrix<T, M, N>>) -> Var<'t, T, Matrix<T, M, N>> {
+        self.add_var(other)
+    }
+
+    pub fn subtract(&self, other: &Var<'t, T, Matrix<T, M, N>>) -> Var<'t, T, Matrix<T, M, N>> {
+        self.sub_var(other)
+    }
+
+    /// Hadamard product
+    pub fn entrywise(&self, other: &Var<'t, T, Matrix<T, M, N>>) -> Var<'t, T, Matrix<T, M, N>> {
+        self.entrywise_var(other)
+    }
+
+    /// Matrix product `AB`
+    pub fn mult<const P: usize>(
+        &self,
+        other: &Var<'t, T, Matrix<T, N, P>>,
+    ) -> Var<'t, T, Matrix<T, M, P>> {
+        self.matmul(other, M, N, P)
+    }
+
+    /// Matrix-vector product `Ax`
+    pub fn mul_vector(&self, vector: &Var<'t, T, Vector<T, N>>) -> Var<'t, T, Vector<T, M>> {
+        self.matmul(vector, M, N, 1)
+    }
+
+    pub fn transpose(&self) -> Var<'t, T, Matrix<T, N, M>> {
+        let input = self.tape.value(self.index);
+        let mut value = vec![T::zero(); M * N];
+
+        for row in 0..M {
+            for col in 0..N {
+                value[col * M + row] = input[row * N + col];
+            }
+        }
+
+        self.tape.push(
+            value,
+            Op::Transpose {
+                input: self.index,
+                rows: M,
+                cols: N,
+            },
+        )
+    }
+}
@@ -0,0 +1,404 @@
+use std::{
+    convert::TryFrom,
+    fmt::Debug,
+    ops::{Add, AddAssign, Index, IndexMut, Mul, MulAssign, Sub, SubAssign},
+};
+
+use num::{FromPrimitive, One, ToPrimitive};
+use rand::{distributions::Standard, prelude::Distribution, Rng};
+
+use crate::{dvector::DVector, error::Error, matrix::gemm::gemm, simd, vector::Vector, Matrix};
+
+/// A matrix whose dimensions are only known at runtime.
+///
+/// The items are stored row-major in one contiguous `Vec`. Operations between two matrices
+/// return `ShapeMismatch` when the dimensions don't fit together instead of being rejected at
+/// compile time like they are for `Matrix`.
+/// ```rust
+/// # use sickmath::*;
+/// let lhs: DMatrix<i32> = DMatrix::new(2, 3, vec![1, 2, 3, 4, 5, 6]).unwrap();
+/// let rhs: DMatrix<i32> = DMatrix::new(3, 1, vec![1, 0, -1]).unwrap();
+///
+/// assert_eq!(lhs.mult(&rhs), DMatrix::new(2, 1, vec![-2, -2]));
+/// assert!(rhs.mult(&rhs).is_err());
+/// ```
+///
+/// Converting from and to a `Matrix` is lossless as long as the dimensions match.
+/// ```rust
+/// # use sickmath::*;
+/// use std::convert::TryFrom;
+///
+/// let matrix: Matrix<u8, 2, 2> = Matrix::new([[1, 2], [3, 4]]);
+/// let dynamic = DMatrix::from(matrix.clone());
+///
+/// assert_eq!(Matrix::<u8, 2, 2>::try_from(dynamic), Ok(matrix));
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct DMatrix<T> {
+    rows: usize,
+    cols: usize,
+    data: Vec<T>,
+}
+
+impl<T> DMatrix<T> {
+    /// Creates a `rows` x `cols` matrix from row-major data. Returns an error if `data` doesn't
+    /// have a length of `rows * cols`
+    pub fn new(rows: usize, cols: usize, data: Vec<T>) -> Result<Self, Error> {
+        if data.len() != rows * cols {
+            return Err(Error::LengthMismatch {
+                expected: rows * cols,
+                found: data.len(),
+            });
+        }
+
+        Ok(DMatrix { rows, cols, data })
+    }
+
+    /// Creates a matrix from its rows. Returns an error if the rows have different lengths
+    pub fn from_rows(rows: Vec<Vec<T>>) -> Result<Self, Error> {
+        let cols = rows.first().map_or(0, Vec::len);
+        let row_count = rows.len();
+        let mut data = Vec::with_capacity(row_count * cols);
+
+        for row in rows {
+            if row.len() != cols {
+                return Err(Error::LengthMismatch {
+                    expected: cols,
+                    found: row.len(),
+                });
+            }
+
+            data.extend(row);
+        }
+
+        Ok(DMatrix {
+            rows: row_count,
+            cols,
+            data,
+        })
+    }
+
+    /// `rows` x `cols` matrix filled with `T::default()`
+    pub fn zeros(rows: usize, cols: usize) -> Self
+    where
+        T: Default + Clone,
+    {
+        DMatrix {
+            rows,
+            cols,
+            data: vec![T::default(); rows * cols],
+        }
+    }
+
+    /// Square matrix with ones on the diagonal and zeros elsewhere
+    pub fn identity(size: usize) -> Self
+    where
+        T: Default + Clone + One,
+    {
+        let mut identity = DMatrix::zeros(size, size);
+
+        for idx in 0..size {
+            identity[(idx, idx)] = T::one();
+        }
+
+        identity
+    }
+
+    pub fn new_random(rows: usize, cols: usize) -> Self
+    where
+        Standard: Distribution<T>,
+    {
+        Self::new_random_with(rows, cols, &mut rand::thread_rng())
+    }
+
+    /// Draws the items in row-major order from `rng`, like `Matrix::new_random_with`
+    pub fn new_random_with(rows: usize, cols: usize, rng: &mut impl Rng) -> Self
+    where
+        Standard: Distribution<T>,
+    {
+        DMatrix {
+            rows,
+            cols,
+            data: (0..rows * cols).map(|_| rng.gen::<T>()).collect(),
+        }
+    }
+
+    pub fn rows(&self) -> usize {
+        self.rows
+    }
+
+    pub fn cols(&self) -> usize {
+        self.cols
+    }
+
+    /// `(rows, cols)`
+    pub fn shape(&self) -> (usize, usize) {
+        (self.rows, self.cols)
+    }
+
+    /// The items in row-major order
+    pub fn as_slice(&self) -> &[T] {
+        &self.data
+    }
+
+    /// Iterates over the rows as slices
+    pub fn iter_rows(&self) -> impl Iterator<Item = &[T]> {
+        (0..self.rows).map(move |row| &self[row])
+    }
+
+    fn check_shape(&self, rhs: &DMatrix<T>) -> Result<(), Error> {
+        if self.shape() == rhs.shape() {
+            Ok(())
+        } else {
+            Err(Error::ShapeMismatch {
+                expected: self.shape(),
+                found: rhs.shape(),
+            })
+        }
+    }
+}
+
+impl<T> DMatrix<T>
+where
+    T: Default
+        + Copy
+        + FromPrimitive
+        + ToPrimitive
+        + Mul<Output = T>
+        + MulAssign
+        + Add<Output = T>
+        + AddAssign
+        + Sub<Output = T>
+        + SubAssign
+        + Debug,
+{
+    pub fn transpose(&self) -> DMatrix<T> {
+        let mut transposed = DMatrix::zeros(self.cols, self.rows);
+
+        for row in 0..self.rows {
+            for col in 0..self.cols {
+                transposed[(col, row)] = self[(row, col)];
+            }
+        }
+
+        transposed
+    }
+
+    /// Matrix product. Returns an error if the columns of `self` don't match the rows of `rhs`
+    pub fn mult(&self, rhs: &DMatrix<T>) -> Result<DMatrix<T>, Error> {
+        if self.cols != rhs.rows {
+            return Err(Error::ShapeMismatch {
+                expected: (self.cols, rhs.cols),
+                found: rhs.shape(),
+            });
+        }
+
+        let mut product = DMatrix::zeros(self.rows, rhs.cols);
+        gemm(
+            self.rows,
+            rhs.cols,
+            self.cols,
+            |row| &self.data[row * self.cols..(row + 1) * self.cols],
+            |row| &rhs.data[row * rhs.cols..(row + 1) * rhs.cols],
+            &mut product.data,
+        );
+
+        Ok(product)
+    }
+
+    /// Matrix-vector product. Returns an error if the length of `vector` isn't the number of columns
+    pub fn mul_vector(&self, vector: &DVector<T>) -> Result<DVector<T>, Error> {
+        if vector.len() != self.cols {
+            return Err(Error::DimensionMismatch {
+                expected: self.cols,
+                found: vector.len(),
+            });
+        }
+
+        let product = self
+            .iter_rows()
+            .map(|row| {
+                simd::dot(row, vector.as_slice()).unwrap_or_else(|| {
+                    row.iter()
+                        .zip(vector.iter())
+                        .fold(T::default(), |acc, (num, vector_num)| {
+                            acc + *num * *vector_num
+                        })
+                })
+            })
+            .collect();
+
+        Ok(DVector::new(product))
+    }
+
+    /// Entrywise addition. Returns an error if the shapes differ
+    pub fn add(&self, rhs: &DMatrix<T>) -> Result<DMatrix<T>, Error> {
+        self.check_shape(rhs)?;
+
+        let mut added = self.clone();
+        if !simd::add_assign(&mut added.data, &rhs.data) {
+            for (num, rhs_num) in added.data.iter_mut().zip(rhs.data.iter()) {
+                *num += *rhs_num;
+            }
+        }
+
+        Ok(added)
+    }
+
+    /// Entrywise subtraction. Returns an error if the shapes differ
+    pub fn subtract(&self, rhs: &DMatrix<T>) -> Result<DMatrix<T>, Error> {
+        self.check_shape(rhs)?;
+
+        let mut subtracted = self.clone();
+        if !simd::sub_assign(&mut subtracted.data, &rhs.data) {
+            for (num, rhs_num) in subtracted.data.iter_mut().zip(rhs.data.iter()) {
+                *num -= *rhs_num;
+            }
+        }
+
+        Ok(subtracted)
+    }
+
+    /// Scalar multiplication
+    pub fn scalar(&self, scalar: T) -> DMatrix<T> {
+        let mut scaled = self.clone();
+        scaled.scalar_mut(scalar);
+
+        scaled
+    }
+
+    /// Mutable scalar multiplication
+    pub fn scalar_mut(&mut self, scalar: T) {
+        if simd::scale(&mut self.data, scalar) {
+            return;
+        }
+
+        for num in self.data.iter_mut() {
+            *num *= scalar;
+        }
+    }
+}
+
+/// Item at `(row, col)`
+impl<T> Index<(usize, usize)> for DMatrix<T> {
+    type Output = T;
+
+    fn index(&self, (row, col): (usize, usize)) -> &Self::Output {
+        assert!(col < self.cols, "Column {} is out of bounds", col);
+        &self.data[row * self.cols + col]
+    }
+}
+
+impl<T> IndexMut<(usize, usize)> for DMatrix<T> {
+    fn index_mut(&mut self, (row, col): (usize, usize)) -> &mut Self::Output {
+        assert!(col < self.cols, "Column {} is out of bounds", col);
+        &mut self.data[row * self.cols + col]
+    }
+}
+
+/// Row as a slice, mirroring the row access of `Matrix`
+impl<T> Index<usize> for DMatrix<T> {
+    type Output = [T];
+
+    fn index(&self, row: usize) -> &Self::Output {
+        &self.data[row * self.cols..(row + 1) * self.cols]
+    }
+}
+
+impl<T> IndexMut<usize> for DMatrix<T> {
+    fn index_mut(&mut self, row: usize) -> &mut Self::Output {
+        &mut self.data[row * self.cols..(row + 1) * self.cols]
+    }
+}
+
+impl<T, const M: usize, const N: usize> From<Matrix<T, M, N>> for DMatrix<T>
+where
+    T: Copy,
+{
+    fn from(matrix: Matrix<T, M, N>) -> Self {
+        DMatrix {
+            rows: M,
+            cols: N,
+            data: matrix.to_row_major(),
+        }
+    }
+}
+
+/// Fails with `ShapeMismatch` if the `DMatrix` isn't `M` x `N`
+impl<T, const M: usize, const N: usize> TryFrom<DMatrix<T>> for Matrix<T, M, N>
+where
+    T: Default + Debug + FromPrimitive + Copy,
+{
+    type Error = Error;
+
+    fn try_from(matrix: DMatrix<T>) -> Result<Self, Self::Error> {
+        if matrix.shape() != (M, N) {
+            return Err(Error::ShapeMismatch {
+                expected: (M, N),
+                found: matrix.shape(),
+            });
+        }
+
+        let rows = (0..M)
+            .map(|row| Vector::try_from(matrix[row].to_vec()))
+            .collect::<Result<Vec<Vector<T, N>>, Error>>()?;
+
+        Matrix::try_new(rows)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn shape_mismatch() {
+        let lhs: DMatrix<f64> = DMatrix::zeros(2, 3);
+        let rhs: DMatrix<f64> = DMatrix::zeros(3, 2);
+
+        assert_eq!(
+            lhs.add(&rhs),
+            Err(Error::ShapeMismatch {
+                expected: (2, 3),
+                found: (3, 2)
+            })
+        );
+        assert!(lhs.mult(&lhs).is_err());
+        assert!(lhs.mul_vector(&DVector::zeros(2)).is_err());
+    }
+
+    #[test]
+    fn matrix_operations() {
+        let lhs: DMatrix<i32> = DMatrix::from_rows(vec![vec![1, 2], vec![3, 4]]).unwrap();
+        let rhs: DMatrix<i32> = DMatrix::from_rows(vec![vec![0, 1], vec![1, 0]]).unwrap();
+
+        assert_eq!(lhs.mult(&rhs), DMatrix::new(2, 2, vec![2, 1, 4, 3]));
+        assert_eq!(lhs.add(&rhs), DMatrix::new(2, 2, vec![1, 3, 4, 4]));
+        assert_eq!(lhs.subtract(&rhs), DMatrix::new(2, 2, vec![1, 1, 2, 4]));
+        assert_eq!(
+            lhs.transpose(),
+            DMatrix::new(2, 2, vec![1, 3, 2, 4]).unwrap()
+        );
+        assert_eq!(
+            lhs.mul_vector(&DVector::new(vec![1, -1])),
+            Ok(DVector::new(vec![-1, -1]))
+        );
+        assert_eq!(&lhs[1], &[3, 4]);
+    }
+
+    #[test]
+    fn static_conversion() {
+        let matrix: Matrix<f64, 2, 3> = Matrix::new([[1.0, 2.0, 3.0], [4.0, 5.0, 6.0]]);
+        let dynamic = DMatrix::from(matrix.clone());
+
+        assert_eq!(dynamic.shape(), (2, 3));
+        assert_eq!(Matrix::<f64, 2, 3>::try_from(dynamic.clone()), Ok(matrix));
+        assert_eq!(
+            Matrix::<f64, 3, 2>::try_from(dynamic),
+            Err(Error::ShapeMismatch {
+                expected: (3, 2),
+                found: (2, 3)
+            })
+        );
+    }
+}
@@ -0,0 +1,422 @@
+use std::{
+    fmt,
+    ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Sub, SubAssign},
+};
+
+use num::{Float, FromPrimitive, One, ToPrimitive, Zero};
+
+use crate::{matrix::Matrix, vector::Vector};
+
+/// A dual number `value + derivative ε` with `ε² = 0`, used for forward-mode differentiation.
+///
+/// `Dual` implements every trait `MathVector` requires, so a `Vector<Dual<f64>, N>` or a
+/// `Matrix<Dual<f64>, M, N>` carries the derivative of each item along with every operation.
+/// Seed the input you differentiate by with `variable` and everything else with `constant`.
+/// ```rust
+/// # use sickmath::*;
+/// let x = Dual::variable(3.0);
+/// let y = x * x + Dual::constant(2.0) * x;
+///
+/// assert_eq!(y, Dual::new(15.0, 8.0));
+/// ```
+#[derive(Debug, Clone, Copy, Default, PartialEq, PartialOrd)]
+pub struct Dual<T> {
+    pub value: T,
+    pub derivative: T,
+}
+
+impl<T> Dual<T> {
+    pub fn new(value: T, derivative: T) -> Self {
+        Dual { value, derivative }
+    }
+
+    /// A number whose derivative is zero
+    pub fn constant(value: T) -> Self
+    where
+        T: Zero,
+    {
+        Dual::new(value, T::zero())
+    }
+
+    /// The number the derivative is taken with respect to
+    pub fn variable(value: T) -> Self
+    where
+        T: One,
+    {
+        Dual::new(value, T::one())
+    }
+}
+
+impl<T> Dual<T>
+where
+    T: Float,
+{
+    /// Applies a function with value `value` and derivative `derivative` at `self.value`
+    fn chain(self, value: T, derivative: T) -> Self {
+        Dual::new(value, derivative * self.derivative)
+    }
+
+    pub fn exp(self) -> Self {
+        let exp = self.value.exp();
+        self.chain(exp, exp)
+    }
+
+    pub fn ln(self) -> Self {
+        self.chain(self.value.ln(), self.value.recip())
+    }
+
+    pub fn sqrt(self) -> Self {
+        let sqrt = self.value.sqrt();
+        self.chain(sqrt, (sqrt + sqrt).recip())
+    }
+
+    pub fn powi(self, n: i32) -> Self {
+        // `x⁰` is constant, `0 * x⁻¹` would be `NaN` at zero
+        if n == 0 {
+            return self.chain(T::one(), T::zero());
+        }
+
+        let n_float = T::from(n).unwrap();
+        self.chain(self.value.powi(n), n_float * self.value.powi(n - 1))
+    }
+
+    pub fn sin(self) -> Self {
+        self.chain(self.value.sin(), self.value.cos())
+    }
+
+    pub fn cos(self) -> Self {
+        self.chain(self.value.cos(), -self.value.sin())
+    }
+
+    pub fn tanh(self) -> Self {
+        let tanh = self.value.tanh();
+        self.chain(tanh, T::one() - tanh * tanh)
+    }
+
+    pub fn recip(self) -> Self {
+        let recip = self.value.recip();
+        self.chain(recip, -recip * recip)
+    }
+
+    /// The derivative at zero is taken to be zero
+    pub fn abs(self) -> Self {
+        let sign = if self.value > T::zero() {
+            T::one()
+        } else if self.value < T::zero() {
+            -T::one()
+        } else {
+            T::zero()
+        };
+        self.chain(self.value.abs(), sign)
+    }
+}
+
+impl<T> Add for Dual<T>
+where
+    T: Add<Output = T>,
+{
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self {
+        Dual::new(self.value + rhs.value, self.derivative + rhs.derivative)
+    }
+}
+
+impl<T> Sub for Dual<T>
+where
+    T: Sub<Output = T>,
+{
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self {
+        Dual::new(self.value - rhs.value, self.derivative - rhs.derivative)
+    }
+}
+
+impl<T> Mul for Dual<T>
+where
+    T: Copy + Add<Output = T> + Mul<Output = T>,
+{
+    type Output = Self;
+
+    fn mul(self, rhs: Self) -> Self {
+        Dual::new(
+            self.value * rhs.value,
+            self.derivative * rhs.value + self.value * rhs.derivative,
+        )
+    }
+}
+
+impl<T> Div for Dual<T>
+where
+    T: Copy + Sub<Output = T> + Mul<Output = T> + Div<Output = T>,
+{
+    type Output = Self;
+
+    fn div(self, rhs: Self) -> Self {
+        Dual::new(
+            self.value / rhs.value,
+            (self.derivative * rhs.value - self.value * rhs.derivative) / (rhs.value * rhs.value),
+        )
+    }
+}
+
+impl<T> Neg for Dual<T>
+where
+    T: Neg<Output = T>,
+{
+    type Output = Self;
+
+    fn neg(self) -> Self {
+        Dual::new(-self.value, -self.derivative)
+    }
+}
+
+impl<T> AddAssign for Dual<T>
+where
+    T: Copy + Add<Output = T>,
+{
+    fn add_assign(&mut self, rhs: Self) {
+        *self = *self + rhs;
+    }
+}
+
+impl<T> SubAssign for Dual<T>
+where
+    T: Copy + Sub<Output = T>,
+{
+    fn sub_assign(&mut self, rhs: Self) {
+        *self = *self - rhs;
+    }
+}
+
+impl<T> MulAssign for Dual<T>
+where
+    T: Copy + Add<Output = T> + Mul<Output = T>,
+{
+    fn mul_assign(&mut self, rhs: Self) {
+        *self = *self * rhs;
+    }
+}
+
+impl<T> DivAssign for Dual<T>
+where
+    T: Copy + Sub<Output = T> + Mul<Output = T> + Div<Output = T>,
+{
+    fn div_assign(&mut self, rhs: Self) {
+        *self = *self / rhs;
+    }
+}
+
+impl<T> Zero for Dual<T>
+where
+    T: Zero,
+{
+    fn zero() -> Self {
+        Dual::new(T::zero(), T::zero())
+    }
+
+    fn is_zero(&self) -> bool {
+        self.value.is_zero() && self.derivative.is_zero()
+    }
+}
+
+impl<T> One for Dual<T>
+where
+    T: Copy + Zero + One,
+{
+    fn one() -> Self {
+        Dual::constant(T::one())
+    }
+}
+
+/// Primitives convert into constants
+impl<T> FromPrimitive for Dual<T>
+where
+    T: FromPrimitive + Zero,
+{
+    fn from_i64(n: i64) -> Option<Self> {
+        T::from_i64(n).map(Dual::constant)
+    }
+
+    fn from_u64(n: u64) -> Option<Self> {
+        T::from_u64(n).map(Dual::constant)
+    }
+
+    fn from_f64(n: f64) -> Option<Self> {
+        T::from_f64(n).map(Dual::constant)
+    }
+}
+
+/// Only the value is converted, the derivative is dropped
+impl<T> ToPrimitive for Dual<T>
+where
+    T: ToPrimitive,
+{
+    fn to_i64(&self) -> Option<i64> {
+        self.value.to_i64()
+    }
+
+    fn to_u64(&self) -> Option<u64> {
+        self.value.to_u64()
+    }
+
+    fn to_f64(&self) -> Option<f64> {
+        self.value.to_f64()
+    }
+}
+
+impl<T> fmt::Display for Dual<T>
+where
+    T: fmt::Display,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} + {}ε", self.value, self.derivative)
+    }
+}
+
+/// Gradient of `f` at `at`, computed with one forward pass per item of `at`
+/// ```rust
+/// # use sickmath::*;
+/// // f(x, y) = x²y
+/// let f = |v: &Vector<Dual<f64>, 2>| v[0] * v[0] * v[1];
+///
+/// assert_eq!(gradient(f, &Vector::new([3.0, 2.0])), Vector::new([12.0, 9.0]));
+/// ```
+pub fn gradient<T, const N: usize>(
+    f: impl Fn(&Vector<Dual<T>, N>) -> Dual<T>,
+    at: &Vector<T, N>,
+) -> Vector<T, N>
+where
+    T: Default + Copy + Zero + One,
+{
+    (0..N).map(|idx| f(&seed(at, idx)).derivative).collect()
+}
+
+/// Jacobian of `f` at `at`, where row `i` is the gradient of the `i`th output
+/// ```rust
+/// # use sickmath::*;
+/// let matrix: Matrix<f64, 2, 3> = Matrix::new([[1.0, 2.0, 3.0], [4.0, 5.0, 6.0]]);
+/// let dual_matrix: Matrix<Dual<f64>, 2, 3> = matrix
+///     .iter()
+///     .map(|row| row.iter().map(|num| Dual::constant(*num)).collect())
+///     .collect();
+///
+/// let jacobian = jacobian(|x| dual_matrix.mul_vector(x), &Vector::new([1.0, 1.0, 1.0]));
+/// assert_eq!(jacobian, matrix);
+/// ```
+pub fn jacobian<T, const M: usize, const N: usize>(
+    f: impl Fn(&Vector<Dual<T>, N>) -> Vector<Dual<T>, M>,
+    at: &Vector<T, N>,
+) -> Matrix<T, M, N>
+where
+    T: Default + Copy + Zero + One,
+{
+    let mut jacobian: Vec<Vec<T>> = vec![Vec::with_capacity(N); M];
+
+    for idx in 0..N {
+        for (row, output) in jacobian.iter_mut().zip(f(&seed(at, idx)).iter()) {
+            row.push(output.derivative);
+        }
+    }
+
+    jacobian
+        .into_iter()
+        .map(|row| row.into_iter().collect())
+        .collect()
+}
+
+/// Turns `at` into constants, except for item `idx` which becomes the variable
+fn seed<T, const N: usize>(at: &Vector<T, N>, idx: usize) -> Vector<Dual<T>, N>
+where
+    T: Default + Copy + Zero + One,
+{
+    at.iter()
+        .enumerate()
+        .map(|(item, num)| {
+            if item == idx {
+                Dual::variable(*num)
+            } else {
+                Dual::constant(*num)
+            }
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::MathVector;
+
+    #[test]
+    fn quotient_rule() {
+        let x = Dual::variable(2.0);
+        let y = (x * x) / (x + Dual::constant(1.0));
+
+        // d/dx x²/(x+1) = (x² + 2x)/(x+1)²
+        assert_eq!(y.value, 4.0 / 3.0);
+        assert!((y.derivative - 8.0 / 9.0).abs() < 1e-15);
+    }
+
+    #[test]
+    fn elementary_functions() {
+        let x = Dual::variable(0.5_f64);
+
+        assert!((x.exp().derivative - 0.5_f64.exp()).abs() < 1e-15);
+        assert!((x.ln().derivative - 2.0).abs() < 1e-15);
+        assert!((x.sqrt().derivative - 0.5 / 0.5_f64.sqrt()).abs() < 1e-15);
+        assert!((x.powi(3).derivative - 0.75).abs() < 1e-15);
+        assert!((x.sin().derivative - 0.5_f64.cos()).abs() < 1e-15);
+        assert!((x.tanh().derivative - (1.0 - 0.5_f64.tanh().powi(2))).abs() < 1e-15);
+    }
+
+    #[test]
+    fn zeroth_power_at_zero() {
+        assert_eq!(Dual::variable(0.0).powi(0), Dual::new(1.0, 0.0));
+        assert_eq!(Dual::variable(2.0).powi(0), Dual::new(1.0, 0.0));
+    }
+
+    #[test]
+    fn vector_dot_gradient() {
+        let weights: Vector<Dual<f64>, 3> = Vector::new([
+            Dual::constant(1.0),
+            Dual::constant(-2.0),
+            Dual::constant(0.5),
+        ]);
+
+        let gradient = gradient(|x| x.dot(&weights).tanh(), &Vector::new([0.1, 0.2, 0.3]));
+        let scale = 1.0 - (0.1_f64 - 0.4 + 0.15).tanh().powi(2);
+
+        for (num, weight) in gradient.iter().zip([1.0, -2.0, 0.5].iter()) {
+            assert!((num - weight * scale).abs() < 1e-15);
+        }
+    }
+
+    #[test]
+    fn matrix_jacobian() {
+        // f(x, y) = (xy, x + y, sin x)
+        let jacobian = jacobian(
+            |v: &Vector<Dual<f64>, 2>| Vector::new([v[0] * v[1], v[0] + v[1], v[0].sin()]),
+            &Vector::new([2.0, 3.0]),
+        );
+
+        assert_eq!(
+            jacobian,
+            Matrix::new([[3.0, 2.0], [1.0, 1.0], [2.0_f64.cos(), 0.0]])
+        );
+    }
+
+    #[test]
+    fn dual_matrix_mult() {
+        let x = Dual::variable(2.0);
+        let c = Dual::constant;
+        let lhs: Matrix<Dual<f64>, 2, 2> = Matrix::new([[x, c(1.0)], [c(0.0), x]]);
+
+        // d/dx of [[x, 1], [0, x]]² = [[2x, 2], [0, 2x]]
+        let squared = lhs.mult(&lhs);
+
+        assert_eq!(squared[0][0], Dual::new(4.0, 4.0));
+        assert_eq!(squared[0][1], Dual::new(4.0, 2.0));
+        assert_eq!(squared[1][1], Dual::new(4.0, 4.0));
+    }
+}
@@ -0,0 +1,351 @@
+use std::{
+    convert::TryFrom,
+    fmt::Debug,
+    ops::{Add, AddAssign, Index, IndexMut, Mul, MulAssign, Sub, SubAssign},
+};
+
+use num::{Float, FromPrimitive, ToPrimitive};
+use rand::{distributions::Standard, prelude::Distribution, Rng};
+
+use crate::{error::Error, simd, vector::Vector};
+
+/// A vector whose length is only known at runtime.
+///
+/// `DVector` supports the same operations as `MathVector`, but since the length of both sides
+/// can't be checked at compile time, every operation between two vectors returns an error
+/// instead of panicking when the lengths differ.
+/// ```rust
+/// # use sickmath::*;
+/// let lhs: DVector<f64> = DVector::new(vec![1.0, 2.0, 3.0]);
+/// let rhs: DVector<f64> = DVector::new(vec![4.0, 5.0, 6.0]);
+///
+/// assert_eq!(lhs.dot(&rhs), Ok(32.0));
+/// assert!(lhs.dot(&DVector::zeros(2)).is_err());
+/// ```
+///
+/// Converting from and to a `Vector` is lossless as long as the lengths match.
+/// ```rust
+/// # use sickmath::*;
+/// use std::convert::TryFrom;
+///
+/// let vector: Vector<u8, 3> = Vector::new([1, 2, 3]);
+/// let dynamic = DVector::from(vector.clone());
+///
+/// assert_eq!(Vector::<u8, 3>::try_from(dynamic), Ok(vector));
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct DVector<T> {
+    data: Vec<T>,
+}
+
+impl<T> DVector<T> {
+    pub fn new(data: Vec<T>) -> Self {
+        DVector { data }
+    }
+
+    /// Vector of length `len` filled with `T::default()`
+    pub fn zeros(len: usize) -> Self
+    where
+        T: Default + Clone,
+    {
+        DVector {
+            data: vec![T::default(); len],
+        }
+    }
+
+    pub fn new_random(len: usize) -> Self
+    where
+        Standard: Distribution<T>,
+    {
+        Self::new_random_with(len, &mut rand::thread_rng())
+    }
+
+    /// Draws the items in order from `rng`
+    pub fn new_random_with(len: usize, rng: &mut impl Rng) -> Self
+    where
+        Standard: Distribution<T>,
+    {
+        DVector {
+            data: (0..len).map(|_| rng.gen::<T>()).collect(),
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.data.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.data.is_empty()
+    }
+
+    pub fn iter(&self) -> std::slice::Iter<'_, T> {
+        self.data.iter()
+    }
+
+    pub fn iter_mut(&mut self) -> std::slice::IterMut<'_, T> {
+        self.data.iter_mut()
+    }
+
+    pub fn as_slice(&self) -> &[T] {
+        &self.data
+    }
+
+    pub fn into_vec(self) -> Vec<T> {
+        self.data
+    }
+
+    fn check_len(&self, rhs: &DVector<T>) -> Result<(), Error> {
+        if self.len() == rhs.len() {
+            Ok(())
+        } else {
+            Err(Error::DimensionMismatch {
+                expected: self.len(),
+                found: rhs.len(),
+            })
+        }
+    }
+}
+
+impl<T> DVector<T>
+where
+    T: Default
+        + Copy
+        + FromPrimitive
+        + ToPrimitive
+        + Mul<Output = T>
+        + MulAssign
+        + Add<Output = T>
+        + AddAssign
+        + Sub<Output = T>
+        + SubAssign
+        + Debug,
+{
+    /// Scalar multiplication
+    pub fn scalar(&self, scalar: T) -> Self {
+        let mut scaled = self.clone();
+        scaled.scalar_mut(scalar);
+
+        scaled
+    }
+
+    /// Mutable scalar multiplication
+    pub fn scalar_mut(&mut self, scalar: T) {
+        if simd::scale(&mut self.data, scalar) {
+            return;
+        }
+
+        for num in self.data.iter_mut() {
+            *num *= scalar;
+        }
+    }
+
+    /// Dot product. Returns an error if the lengths differ
+    pub fn dot(&self, rhs: &DVector<T>) -> Result<T, Error> {
+        self.check_len(rhs)?;
+
+        if let Some(product) = simd::dot(&self.data, &rhs.data) {
+            return Ok(product);
+        }
+
+        let mut acc: T = T::default();
+
+        for (num, rhs_num) in self.data.iter().zip(rhs.data.iter()) {
+            acc += *num * *rhs_num;
+        }
+
+        Ok(acc)
+    }
+
+    /// Vector addition. Returns an error if the lengths differ
+    pub fn add_vector(&self, rhs: &DVector<T>) -> Result<Self, Error> {
+        let mut added = self.clone();
+        added.add_vector_mut(rhs)?;
+
+        Ok(added)
+    }
+
+    /// Mutable vector addition. Returns an error if the lengths differ
+    pub fn add_vector_mut(&mut self, rhs: &DVector<T>) -> Result<(), Error> {
+        self.check_len(rhs)?;
+
+        if !simd::add_assign(&mut self.data, &rhs.data) {
+            for (num, rhs_num) in self.data.iter_mut().zip(rhs.data.iter()) {
+                *num += *rhs_num;
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Vector subtraction. Returns an error if the lengths differ
+    pub fn sub_vector(&self, rhs: &DVector<T>) -> Result<Self, Error> {
+        let mut subtracted = self.clone();
+        subtracted.sub_vector_mut(rhs)?;
+
+        Ok(subtracted)
+    }
+
+    /// Mutable vector subtraction. Returns an error if the lengths differ
+    pub fn sub_vector_mut(&mut self, rhs: &DVector<T>) -> Result<(), Error> {
+        self.check_len(rhs)?;
+
+        if !simd::sub_assign(&mut self.data, &rhs.data) {
+            for (num, rhs_num) in self.data.iter_mut().zip(rhs.data.iter()) {
+                *num -= *rhs_num;
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Entrywise vector multiplication. Returns an error if the lengths differ
+    pub fn entrywise(&self, rhs: &DVector<T>) -> Result<Self, Error> {
+        let mut multiplied = self.clone();
+        multiplied.entrywise_mut(rhs)?;
+
+        Ok(multiplied)
+    }
+
+    /// Mutable entrywise vector multiplication. Returns an error if the lengths differ
+    pub fn entrywise_mut(&mut self, rhs: &DVector<T>) -> Result<(), Error> {
+        self.check_len(rhs)?;
+
+        if !simd::mul_assign(&mut self.data, &rhs.data) {
+            for (num, rhs_num) in self.data.iter_mut().zip(rhs.data.iter()) {
+                *num *= *rhs_num;
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Cross product. Returns an error if either vector has a length other than 3
+    pub fn cross(&self, rhs: &DVector<T>) -> Result<Self, Error> {
+        for len in [self.len(), rhs.len()] {
+            if len != 3 {
+                return Err(Error::DimensionMismatch {
+                    expected: 3,
+                    found: len,
+                });
+            }
+        }
+
+        let (lhs, rhs) = (&self.data, &rhs.data);
+
+        Ok(DVector::new(vec![
+            lhs[1] * rhs[2] - lhs[2] * rhs[1],
+            lhs[2] * rhs[0] - lhs[0] * rhs[2],
+            lhs[0] * rhs[1] - lhs[1] * rhs[0],
+        ]))
+    }
+
+    /// Euclidean norm of the vector. Only available for floating point types
+    pub fn magnitude(&self) -> T
+    where
+        T: Float,
+    {
+        self.data
+            .iter()
+            .fold(T::zero(), |acc, num| acc + *num * *num)
+            .sqrt()
+    }
+
+    /// Sum of all items
+    pub fn sum(&self) -> T {
+        let mut acc: T = T::default();
+
+        for num in self.data.iter() {
+            acc += *num;
+        }
+
+        acc
+    }
+}
+
+impl<T> Index<usize> for DVector<T> {
+    type Output = T;
+
+    fn index(&self, idx: usize) -> &Self::Output {
+        &self.data[idx]
+    }
+}
+
+impl<T> IndexMut<usize> for DVector<T> {
+    fn index_mut(&mut self, idx: usize) -> &mut Self::Output {
+        &mut self.data[idx]
+    }
+}
+
+impl<T> From<Vec<T>> for DVector<T> {
+    fn from(data: Vec<T>) -> Self {
+        DVector::new(data)
+    }
+}
+
+impl<T, const N: usize> From<Vector<T, N>> for DVector<T>
+where
+    T: Copy,
+{
+    fn from(vector: Vector<T, N>) -> Self {
+        DVector::new(vector.iter().copied().collect())
+    }
+}
+
+/// Fails with `LengthMismatch` if the length of the `DVector` isn't `N`
+impl<T, const N: usize> TryFrom<DVector<T>> for Vector<T, N> {
+    type Error = Error;
+
+    fn try_from(vector: DVector<T>) -> Result<Self, Self::Error> {
+        Vector::try_from(vector.data)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn length_mismatch() {
+        let mut lhs: DVector<i32> = DVector::new(vec![1, 2, 3]);
+        let rhs: DVector<i32> = DVector::new(vec![1, 2]);
+
+        assert_eq!(
+            lhs.add_vector_mut(&rhs),
+            Err(Error::DimensionMismatch {
+                expected: 3,
+                found: 2
+            })
+        );
+        assert_eq!(lhs, DVector::new(vec![1, 2, 3]));
+    }
+
+    #[test]
+    fn vector_operations() {
+        let lhs: DVector<f64> = DVector::new(vec![1.0, 2.0, 3.0]);
+        let rhs: DVector<f64> = DVector::new(vec![0.5, -1.0, 2.0]);
+
+        assert_eq!(lhs.add_vector(&rhs), Ok(DVector::new(vec![1.5, 1.0, 5.0])));
+        assert_eq!(lhs.sub_vector(&rhs), Ok(DVector::new(vec![0.5, 3.0, 1.0])));
+        assert_eq!(lhs.entrywise(&rhs), Ok(DVector::new(vec![0.5, -2.0, 6.0])));
+        assert_eq!(lhs.cross(&rhs), Ok(DVector::new(vec![7.0, -0.5, -2.0])));
+        assert_eq!(lhs.scalar(2.0), DVector::new(vec![2.0, 4.0, 6.0]));
+        assert_eq!(lhs.sum(), 6.0);
+    }
+
+    #[test]
+    fn static_conversion() {
+        let vector: Vector<u8, 3> = Vector::new([1, 2, 3]);
+
+        assert_eq!(
+            Vector::<u8, 3>::try_from(DVector::from(vector.clone())),
+            Ok(vector)
+        );
+        assert_eq!(
+            Vector::<u8, 2>::try_from(DVector::new(vec![1, 2, 3])),
+            Err(Error::LengthMismatch {
+                expected: 2,
+                found: 3
+            })
+        );
+    }
+}
@@ -0,0 +1,104 @@
+use std::fmt;
+
+/// Errors returned by the fallible `try_` methods and the matrix decompositions.
+///
+/// The infallible counterparts of the `try_` methods will panic with the same message.
+#[derive(Debug, Clone, PartialEq)]
+#[non_exhaustive]
+pub enum Error {
+    /// The input had a different length than the const generic length it was converted into
+    LengthMismatch { expected: usize, found: usize },
+
+    /// The operation is only defined for a certain dimension
+    DimensionMismatch { expected: usize, found: usize },
+
+    /// The matrix had a different number of rows and columns than required, given as `(rows, cols)`
+    ShapeMismatch {
+        expected: (usize, usize),
+        found: (usize, usize),
+    },
+
+    /// The `(row, col)` index lies outside of a matrix with the given `(rows, cols)`
+    IndexOutOfBounds {
+        index: (usize, usize),
+        shape: (usize, usize),
+    },
+
+    /// The Jacobi preconditioner needs the diagonal of the operator and that diagonal has no zeros
+    InvalidPreconditioner,
+
+    /// A distribution parameter was out of range, such as a negative standard deviation or a
+    /// probability outside of `[0, 1]`
+    InvalidDistribution,
+
+    /// An arithmetic operation overflowed the element type
+    Overflow,
+
+    /// The matrix has no inverse
+    SingularMatrix,
+
+    /// The matrix is not symmetric positive definite
+    NotPositiveDefinite,
+
+    /// The matrix is not symmetric
+    NotSymmetric,
+
+    /// An iterative algorithm didn't converge within its iteration limit
+    NotConverged { iterations: usize },
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Error::LengthMismatch { expected, found } => {
+                write!(f, "Expected a length of {} but it was {}", expected, found)
+            }
+            Error::DimensionMismatch { expected, found } => write!(
+                f,
+                "Expected a dimension of {} but it was {}",
+                expected, found
+            ),
+            Error::ShapeMismatch { expected, found } => write!(
+                f,
+                "Expected a {}x{} matrix but it was {}x{}",
+                expected.0, expected.1, found.0, found.1
+            ),
+            Error::IndexOutOfBounds { index, shape } => write!(
+                f,
+                "Index ({}, {}) is out of bounds for a {}x{} matrix",
+                index.0, index.1, shape.0, shape.1
+            ),
+            Error::InvalidPreconditioner => write!(
+                f,
+                "The Jacobi preconditioner needs a known diagonal without zeros"
+            ),
+            Error::InvalidDistribution => write!(f, "The distribution parameters are out of range"),
+            Error::Overflow => write!(f, "Arithmetic overflow"),
+            Error::SingularMatrix => write!(f, "The matrix is singular"),
+            Error::NotPositiveDefinite => {
+                write!(f, "The matrix is not symmetric positive definite")
+            }
+            Error::NotSymmetric => write!(f, "The matrix is not symmetric"),
+            Error::NotConverged { iterations } => {
+                write!(f, "Did not converge after {} iterations", iterations)
+            }
+        }
+    }
+}
+
+impl std::error::Error for Error {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn display_length_mismatch() {
+        let error = Error::LengthMismatch {
+            expected: 3,
+            found: 2,
+        };
+
+        assert_eq!(error.to_string(), "Expected a length of 3 but it was 2");
+    }
+}
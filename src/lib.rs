@@ -2,12 +2,52 @@
 //!
 //! Provides a fast and scalable Vector and Matrix implementation.
 
+/// Neural network activation functions
+mod activation;
+pub use activation::Activation;
+/// Reverse-mode automatic differentiation over `Vector` and `Matrix`
+mod autodiff;
+pub use autodiff::{check_gradient, Differentiable, Gradients, Tape, Var};
+/// Matrix with dimensions chosen at runtime
+mod dmatrix;
+pub use dmatrix::DMatrix;
+/// Dual numbers for forward-mode differentiation
+mod dual;
+pub use dual::{gradient, jacobian, Dual};
+/// Vector with a length chosen at runtime
+mod dvector;
+pub use dvector::DVector;
+/// Errors returned by fallible operations
+mod error;
+pub use error::Error;
+/// Loss functions returning the loss and its gradient
+mod loss;
+pub use loss::{Loss, LossReport, Reduction};
 /// Implement your own Vector type
 mod math_vector;
 pub use math_vector::MathVector;
 /// Multiple vectors wrapped in an array
 mod matrix;
-pub use matrix::Matrix;
+pub use matrix::{Cholesky, Lu, Matrix, Svd};
+/// Splits matrix operations across threads
+mod parallel;
+pub use parallel::ParallelElement;
+#[cfg(feature = "parallel")]
+pub use parallel::{num_threads, set_num_threads};
+/// Seeded random number generation and distributions for initializing weights
+mod random;
+pub use random::{Bernoulli, Normal};
+/// Iterative solvers for linear systems
+mod solver;
+pub use solver::{
+    bicgstab, conjugate_gradient, gmres, LinearOperator, Preconditioner, SolveReport,
+    SolverOptions,
+};
+/// Sparse matrices in coordinate, compressed row and compressed column format
+mod sparse;
+pub use sparse::{CooMatrix, CscMatrix, CsrMatrix};
+/// Vectorized kernels used by the `MathVector` implementations
+mod simd;
 /// Supports both `SmallVector` and `LargeVector`
 mod vector;
 pub use vector::Vector;
@@ -0,0 +1,431 @@
+use num::Float;
+
+use crate::{activation::Activation, matrix::Matrix, vector::Vector};
+
+/// How the losses of all items are combined into one number
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Reduction {
+    /// Average of the losses
+    Mean,
+    /// Sum of the losses
+    Sum,
+    /// Keep every loss on its own
+    None,
+}
+
+/// Outcome of a loss function
+#[derive(Debug, Clone, PartialEq)]
+pub struct LossReport<T, L, G> {
+    /// The reduced loss, `None` for `Reduction::None`
+    pub loss: Option<T>,
+    /// Loss of every item before the reduction
+    pub losses: L,
+    /// Gradient of the reduced loss with respect to the predictions. Without a reduction it's the
+    /// gradient of every loss with respect to its own predictions, which is the same as for
+    /// `Reduction::Sum`
+    pub gradient: G,
+}
+
+/// Loss functions comparing predictions in `self` with `targets`.
+///
+/// A `Vector` is a single sample and a `Matrix<T, B, N>` a batch of `B` samples, one per row.
+/// MSE, MAE, Huber and hinge have a loss for every item, cross-entropy has one for every sample,
+/// and `Reduction::Mean` averages over those losses.
+/// ```rust
+/// # use sickmath::*;
+/// let predictions: Matrix<f64, 2, 2> = Matrix::new([[1.0, 2.0], [3.0, 4.0]]);
+/// let targets: Matrix<f64, 2, 2> = Matrix::new([[1.0, 1.0], [1.0, 2.0]]);
+///
+/// let report = predictions.mse(&targets, Reduction::Mean);
+///
+/// assert_eq!(report.loss, Some(2.25));
+/// assert_eq!(report.gradient, Matrix::new([[0.0, 0.5], [1.0, 1.0]]));
+/// ```
+pub trait Loss<T>: Sized
+where
+    T: Float,
+{
+    /// Loss of every sample
+    type Samples;
+
+    /// Mean squared error `(prediction - target)²`
+    fn mse(&self, targets: &Self, reduction: Reduction) -> LossReport<T, Self, Self>;
+
+    /// Mean absolute error `|prediction - target|`, with a gradient of zero where they're equal
+    fn mae(&self, targets: &Self, reduction: Reduction) -> LossReport<T, Self, Self>;
+
+    /// Huber loss, squared for errors up to `delta` and linear beyond
+    fn huber(&self, targets: &Self, delta: T, reduction: Reduction) -> LossReport<T, Self, Self>;
+
+    /// Binary hinge loss `max(0, 1 - prediction * target)` with targets of `-1` or `1`
+    fn hinge(&self, targets: &Self, reduction: Reduction) -> LossReport<T, Self, Self>;
+
+    /// Cross-entropy between the softmax of the logits in `self` and the class probabilities in
+    /// `targets`, usually one-hot.
+    ///
+    /// It's computed from `log_softmax`, so large logits don't overflow and classes masked with
+    /// a logit of `-inf` are fine as long as their target is zero.
+    fn cross_entropy(
+        &self,
+        targets: &Self,
+        reduction: Reduction,
+    ) -> LossReport<T, Self::Samples, Self>;
+}
+
+impl<T, const N: usize> Loss<T> for Vector<T, N>
+where
+    T: Default + Copy + Float,
+{
+    type Samples = T;
+
+    fn mse(&self, targets: &Self, reduction: Reduction) -> LossReport<T, Self, Self> {
+        self.pointwise(targets, reduction, squared_error)
+    }
+
+    fn mae(&self, targets: &Self, reduction: Reduction) -> LossReport<T, Self, Self> {
+        self.pointwise(targets, reduction, absolute_error)
+    }
+
+    fn huber(&self, targets: &Self, delta: T, reduction: Reduction) -> LossReport<T, Self, Self> {
+        self.pointwise(targets, reduction, |prediction, target| {
+            huber_error(prediction, target, delta)
+        })
+    }
+
+    fn hinge(&self, targets: &Self, reduction: Reduction) -> LossReport<T, Self, Self> {
+        self.pointwise(targets, reduction, hinge_error)
+    }
+
+    fn cross_entropy(&self, targets: &Self, reduction: Reduction) -> LossReport<T, T, Self> {
+        let (loss, gradient) = self.sample_cross_entropy(targets);
+
+        reduce(loss, loss, 1, gradient, reduction)
+    }
+}
+
+impl<T, const B: usize, const N: usize> Loss<T> for Matrix<T, B, N>
+where
+    T: Default + Copy + Float,
+{
+    type Samples = Vector<T, B>;
+
+    fn mse(&self, targets: &Self, reduction: Reduction) -> LossReport<T, Self, Self> {
+        self.pointwise(targets, reduction, squared_error)
+    }
+
+    fn mae(&self, targets: &Self, reduction: Reduction) -> LossReport<T, Self, Self> {
+        self.pointwise(targets, reduction, absolute_error)
+    }
+
+    fn huber(&self, targets: &Self, delta: T, reduction: Reduction) -> LossReport<T, Self, Self> {
+        self.pointwise(targets, reduction, |prediction, target| {
+            huber_error(prediction, target, delta)
+        })
+    }
+
+    fn hinge(&self, targets: &Self, reduction: Reduction) -> LossReport<T, Self, Self> {
+        self.pointwise(targets, reduction, hinge_error)
+    }
+
+    fn cross_entropy(
+        &self,
+        targets: &Self,
+        reduction: Reduction,
+    ) -> LossReport<T, Vector<T, B>, Self> {
+        let (losses, gradient): (Vec<T>, Vec<Vector<T, N>>) = self
+            .iter()
+            .zip(targets.iter())
+            .map(|(logits, target)| logits.sample_cross_entropy(target))
+            .unzip();
+        let total = losses.iter().fold(T::zero(), |acc, loss| acc + *loss);
+
+        reduce(
+            losses.into_iter().collect(),
+            total,
+            B,
+            gradient.into_iter().collect(),
+            reduction,
+        )
+    }
+}
+
+impl<T, const N: usize> Vector<T, N>
+where
+    T: Default + Copy + Float,
+{
+    /// Applies `error`, returning the loss and its derivative, to every prediction and target
+    fn pointwise(
+        &self,
+        targets: &Self,
+        reduction: Reduction,
+        error: impl Fn(T, T) -> (T, T),
+    ) -> LossReport<T, Self, Self> {
+        let (losses, gradient) = self.pointwise_errors(targets, &error);
+        let total = losses.iter().fold(T::zero(), |acc, loss| acc + *loss);
+
+        reduce(losses, total, N, gradient, reduction)
+    }
+
+    fn pointwise_errors(&self, targets: &Self, error: impl Fn(T, T) -> (T, T)) -> (Self, Self) {
+        let (losses, gradient): (Vec<T>, Vec<T>) = self
+            .iter()
+            .zip(targets.iter())
+            .map(|(prediction, target)| error(*prediction, *target))
+            .unzip();
+
+        (losses.into_iter().collect(), gradient.into_iter().collect())
+    }
+
+    /// Cross-entropy of a single sample and its gradient `softmax(logits) * sum(targets) - targets`
+    fn sample_cross_entropy(&self, targets: &Self) -> (T, Self) {
+        let log_softmax = self.log_softmax();
+        let target_sum = targets.iter().fold(T::zero(), |acc, num| acc + *num);
+
+        // Skipping zero targets keeps masked classes from turning the loss into `0 * -inf = NaN`
+        let loss = targets
+            .iter()
+            .zip(log_softmax.iter())
+            .filter(|(target, _)| !target.is_zero())
+            .fold(T::zero(), |acc, (target, log_prob)| {
+                acc - *target * *log_prob
+            });
+
+        let gradient = self
+            .softmax()
+            .iter()
+            .zip(targets.iter())
+            .map(|(prob, target)| *prob * target_sum - *target)
+            .collect();
+
+        (loss, gradient)
+    }
+}
+
+impl<T, const B: usize, const N: usize> Matrix<T, B, N>
+where
+    T: Default + Copy + Float,
+{
+    fn pointwise(
+        &self,
+        targets: &Self,
+        reduction: Reduction,
+        error: impl Fn(T, T) -> (T, T),
+    ) -> LossReport<T, Self, Self> {
+        let (losses, gradient): (Vec<Vector<T, N>>, Vec<Vector<T, N>>) = self
+            .iter()
+            .zip(targets.iter())
+            .map(|(predictions, targets)| predictions.pointwise_errors(targets, &error))
+            .unzip();
+        let total = losses
+            .iter()
+            .flatten()
+            .fold(T::zero(), |acc, loss| acc + *loss);
+
+        reduce(
+            losses.into_iter().collect(),
+            total,
+            B * N,
+            gradient.into_iter().collect(),
+            reduction,
+        )
+    }
+}
+
+/// Applies `reduction` to the sum of `count` losses, scaling `gradient` to match
+fn reduce<T, L, G>(
+    losses: L,
+    total: T,
+    count: usize,
+    mut gradient: G,
+    reduction: Reduction,
+) -> LossReport<T, L, G>
+where
+    T: Float,
+    G: Gradient<T>,
+{
+    let loss = match reduction {
+        Reduction::Mean => {
+            let count = T::from(count).unwrap();
+            gradient.divide_mut(count);
+            Some(total / count)
+        }
+        Reduction::Sum => Some(total),
+        Reduction::None => None,
+    };
+
+    LossReport {
+        loss,
+        losses,
+        gradient,
+    }
+}
+
+/// Gradients that can be scaled down for `Reduction::Mean`
+trait Gradient<T> {
+    fn divide_mut(&mut self, divisor: T);
+}
+
+impl<T, const N: usize> Gradient<T> for Vector<T, N>
+where
+    T: Float,
+{
+    fn divide_mut(&mut self, divisor: T) {
+        for num in self.iter_mut() {
+            *num = *num / divisor;
+        }
+    }
+}
+
+impl<T, const M: usize, const N: usize> Gradient<T> for Matrix<T, M, N>
+where
+    T: Float,
+{
+    fn divide_mut(&mut self, divisor: T) {
+        for row in self.iter_mut() {
+            row.divide_mut(divisor);
+        }
+    }
+}
+
+fn squared_error<T: Float>(prediction: T, target: T) -> (T, T) {
+    let error = prediction - target;
+    (error * error, error + error)
+}
+
+fn absolute_error<T: Float>(prediction: T, target: T) -> (T, T) {
+    let error = prediction - target;
+    let derivative = if error.is_zero() {
+        T::zero()
+    } else {
+        error.signum()
+    };
+
+    (error.abs(), derivative)
+}
+
+fn huber_error<T: Float>(prediction: T, target: T, delta: T) -> (T, T) {
+    let error = prediction - target;
+    let half = T::from(0.5).unwrap();
+
+    if error.abs() <= delta {
+        (half * error * error, error)
+    } else {
+        (delta * (error.abs() - half * delta), delta * error.signum())
+    }
+}
+
+fn hinge_error<T: Float>(prediction: T, target: T) -> (T, T) {
+    let margin = T::one() - prediction * target;
+
+    if margin > T::zero() {
+        (margin, -target)
+    } else {
+        (T::zero(), T::zero())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Loss, Reduction};
+    use crate::{Activation, MathVector, Matrix, Tape, Vector};
+
+    #[test]
+    fn reductions() {
+        let predictions: Vector<f64, 4> = Vector::new([1.0, -2.0, 0.5, 3.0]);
+        let targets: Vector<f64, 4> = Vector::new([0.0, -2.0, 1.5, 1.0]);
+
+        let none = predictions.mae(&targets, Reduction::None);
+        let sum = predictions.mae(&targets, Reduction::Sum);
+        let mean = predictions.mae(&targets, Reduction::Mean);
+
+        assert_eq!(none.loss, None);
+        assert_eq!(none.losses, Vector::new([1.0, 0.0, 1.0, 2.0]));
+        assert_eq!(none.gradient, Vector::new([1.0, 0.0, -1.0, 1.0]));
+        assert_eq!(sum.loss, Some(4.0));
+        assert_eq!(sum.gradient, none.gradient);
+        assert_eq!(mean.loss, Some(1.0));
+        assert_eq!(mean.gradient, Vector::new([0.25, 0.0, -0.25, 0.25]));
+    }
+
+    #[test]
+    fn huber_and_hinge() {
+        let predictions: Vector<f64, 3> = Vector::new([0.5, 3.0, -0.2]);
+        let targets: Vector<f64, 3> = Vector::new([0.0, 1.0, 1.0]);
+
+        let huber = predictions.huber(&targets, 1.0, Reduction::None);
+        assert_eq!(huber.losses, Vector::new([0.125, 1.5, 0.7]));
+        assert_eq!(huber.gradient, Vector::new([0.5, 1.0, -1.0]));
+
+        let targets: Vector<f64, 3> = Vector::new([1.0, 1.0, -1.0]);
+        let hinge = predictions.hinge(&targets, Reduction::Sum);
+        assert_eq!(hinge.loss, Some(0.5 + 0.8));
+        assert_eq!(hinge.gradient, Vector::new([-1.0, 0.0, 1.0]));
+    }
+
+    #[test]
+    fn gradients_match_autodiff() {
+        let predictions: Vector<f64, 3> = Vector::new([0.3, -1.0, 2.0]);
+        let targets: Vector<f64, 3> = Vector::new([1.0, -0.5, 0.0]);
+
+        let report = predictions.mse(&targets, Reduction::Mean);
+
+        let tape = Tape::new();
+        let x = tape.var(predictions.clone());
+        let mse = x
+            .sub_vector(&tape.var(targets))
+            .square()
+            .sum()
+            .scalar(1.0 / 3.0);
+
+        assert_eq!(report.loss, Some(mse.value()));
+        assert_eq!(report.gradient, mse.backward().wrt(&x));
+    }
+
+    #[test]
+    fn cross_entropy_gradient() {
+        let logits: Vector<f64, 3> = Vector::new([2.0, -1.0, 0.5]);
+        let targets: Vector<f64, 3> = Vector::new([0.0, 1.0, 0.0]);
+
+        let report = logits.cross_entropy(&targets, Reduction::Mean);
+
+        assert_eq!(report.loss, Some(-logits.log_softmax()[1]));
+
+        let tape = Tape::new();
+        let x = tape.var(logits.clone());
+        let loss = x.exp().sum().ln().sub(&x.dot(&tape.var(targets.clone())));
+        let expected = loss.backward().wrt(&x);
+        for (num, expected) in report.gradient.iter().zip(expected.iter()) {
+            assert!((num - expected).abs() < 1e-15);
+        }
+    }
+
+    #[test]
+    fn cross_entropy_with_masked_and_large_logits() {
+        let inf = f32::INFINITY;
+        let logits: Vector<f32, 3> = Vector::new([1000.0, -inf, 1000.0]);
+        let targets: Vector<f32, 3> = Vector::new([1.0, 0.0, 0.0]);
+
+        let report = logits.cross_entropy(&targets, Reduction::Sum);
+
+        assert!((report.loss.unwrap() - 2.0_f32.ln()).abs() < 1e-4);
+        assert_eq!(report.gradient, Vector::new([-0.5, 0.0, 0.5]));
+    }
+
+    #[test]
+    fn batched_losses() {
+        let logits: Matrix<f64, 2, 2> = Matrix::new([[0.0, 0.0], [3.0, 1.0]]);
+        let targets: Matrix<f64, 2, 2> = Matrix::new([[1.0, 0.0], [0.0, 1.0]]);
+
+        let report = logits.cross_entropy(&targets, Reduction::Mean);
+        let first = logits[0].cross_entropy(&targets[0], Reduction::Mean);
+        let second = logits[1].cross_entropy(&targets[1], Reduction::Mean);
+
+        assert_eq!(report.losses, Vector::new([first.losses, second.losses]));
+        assert_eq!(report.loss, Some((first.losses + second.losses) / 2.0));
+        assert_eq!(report.gradient[1], second.gradient.scalar(0.5));
+
+        let mse = logits.mse(&targets, Reduction::Mean);
+        assert_eq!(mse.loss, Some((1.0 + 0.0 + 9.0 + 0.0) / 4.0));
+        assert_eq!(mse.gradient, Matrix::new([[-0.5, 0.0], [1.5, 0.0]]));
+    }
+}
@@ -1,25 +1,38 @@
 use std::ops::Index;
 
-use crate::matrix::Matrix;
+use num::{CheckedAdd, CheckedMul, Float};
+
+use crate::{error::Error, matrix::Matrix};
 
 /// A trait for mathematical vectors.
 ///
 ///  Implementing `MathVector` and `Index` on a data type makes it possible to perform mathematical
 ///  operations with other data types that implements `MathVector` such as `Vector`.
 ///
+///  Scalars, dot products and sums are all expressed in the element type `T`, so a vector of
+///  floats will never be truncated to an integer.
+///
 ///  ## Mutable methods
 ///  Most methods will have a mutable alternative which will mutate the right hand side argument instead of creating
 ///  a new one.
 ///  This vastly increases the speed of the operation and should be used in most cases.
+///
+///  ## Element types
+///  `f32`, `f64` and `i32` use SIMD kernels picked at runtime, every other `T` a scalar loop.
 pub trait MathVector<T, const N: usize> {
     /// Scalar multiplication
-    fn scalar(&self, scalar: isize) -> Self;
+    fn scalar(&self, scalar: T) -> Self;
 
     /// Mutable scalar multiplication
-    fn scalar_mut(&mut self, scalar: isize);
+    fn scalar_mut(&mut self, scalar: T);
 
-    /// Dot product. Will return an `isize`
-    fn dot(&self, rhs: &(impl MathVector<T, N> + Index<usize, Output = T>)) -> isize;
+    /// Dot product
+    fn dot(&self, rhs: &(impl MathVector<T, N> + Index<usize, Output = T>)) -> T;
+
+    /// Dot product. Returns an error instead of overflowing the element type
+    fn try_dot(&self, rhs: &(impl MathVector<T, N> + Index<usize, Output = T>)) -> Result<T, Error>
+    where
+        T: CheckedAdd + CheckedMul;
 
     /// Vector addition
     fn add_vector(&self, rhs: &(impl MathVector<T, N> + Index<usize, Output = T>)) -> Self;
@@ -45,15 +58,39 @@ pub trait MathVector<T, const N: usize> {
     /// Mutable cross product. Will panic if vector has a length other than 3
     fn cross_mut(&mut self, rhs: &(impl MathVector<T, N> + Index<usize, Output = T>));
 
+    /// Cross product. Returns an error if vector has a length other than 3
+    fn try_cross(
+        &self,
+        rhs: &(impl MathVector<T, N> + Index<usize, Output = T>),
+    ) -> Result<Self, Error>
+    where
+        Self: Sized;
+
+    /// Mutable cross product. Returns an error if vector has a length other than 3
+    fn try_cross_mut(
+        &mut self,
+        rhs: &(impl MathVector<T, N> + Index<usize, Output = T>),
+    ) -> Result<(), Error>;
+
     /// Tensor product. Will return a `Matrix` instead of `Self`
     fn tensor_prod<const M: usize>(
         &self,
         rhs: &(impl MathVector<T, N> + Index<usize, Output = T>),
     ) -> Matrix<T, M, N>;
 
-    /// Magnitude of the vector
-    fn magnitude(&self) -> usize;
+    /// Euclidean norm of the vector. Only available for floating point types
+    fn magnitude(&self) -> T
+    where
+        T: Float;
 
     /// Sum of all items
-    fn sum(&self) -> isize;
+    fn sum(&self) -> T;
+
+    /// The items as one contiguous slice, if the implementor stores them that way.
+    ///
+    /// Returning a slice lets the vectorized kernels for `f32`, `f64` and `i32` read the right
+    /// hand side of an operation directly. The default returns `None`, which always works.
+    fn as_slice(&self) -> Option<&[T]> {
+        None
+    }
 }
@@ -1,28 +1,66 @@
+pub mod cholesky;
+pub mod eigen;
+pub(crate) mod gemm;
+pub mod init;
 pub mod into_2d_vector;
+pub mod inverse;
 pub mod iterator;
+mod jacobi;
+pub mod lu;
 pub mod math;
+pub mod math_ops;
+pub mod qr;
+mod storage;
+pub mod svd;
 pub mod transpose;
 
-use std::{fmt::Debug, ops::Index};
+pub use cholesky::Cholesky;
+pub use lu::Lu;
+pub use svd::Svd;
 
-use crate::vector::Vector;
+use std::{
+    fmt::Debug,
+    ops::{Index, IndexMut},
+};
+
+use crate::{error::Error, random::seeded_rng, vector::Vector};
 use into_2d_vector::Into2dVector;
-use num::FromPrimitive;
-use rand::{distributions::Standard, prelude::Distribution};
+use num::{FromPrimitive, One};
+use rand::{distributions::Standard, prelude::Distribution, Rng};
+use storage::Storage;
 
+/// A `M` x `N` matrix made of `M` row vectors.
+///
+/// The rows live in a single heap allocation, so even large matrices such as
+/// `Matrix<f64, 1000, 1000>` can be created without overflowing the stack. `matrix[row]`
+/// returns the row as a `Vector`.
 #[derive(PartialEq, Debug, Clone)]
 pub struct Matrix<T, const M: usize, const N: usize> {
-    inner: [Vector<T, N>; M],
+    inner: Storage<T, M, N>,
 }
 
 impl<T, const M: usize, const N: usize> Matrix<T, M, N> {
     pub fn new(inner: impl Into2dVector<T, M, N>) -> Matrix<T, M, N> {
-        Matrix {
-            inner: inner.into_2d_vector(),
-        }
+        Matrix::try_new(inner).unwrap_or_else(|err: Error| panic!("{}", err))
+    }
+
+    pub fn try_new(inner: impl Into2dVector<T, M, N>) -> Result<Matrix<T, M, N>, Error> {
+        Ok(Matrix {
+            inner: Storage::try_from_rows(inner.try_into_rows()?)?,
+        })
     }
 
     pub fn new_random() -> Matrix<T, M, N>
+    where
+        T: Default + FromPrimitive + Debug + Copy,
+        Standard: Distribution<T>,
+    {
+        Matrix::new_random_with(&mut rand::thread_rng())
+    }
+
+    /// Same as `new_random` but draws the items in row-major order from `rng`, so the same state
+    /// of `rng` gives the same items as `DMatrix::new_random_with` for an `M` x `N` matrix.
+    pub fn new_random_with(rng: &mut impl Rng) -> Matrix<T, M, N>
     where
         T: Default + FromPrimitive + Debug + Copy,
         Standard: Distribution<T>,
@@ -30,12 +68,90 @@ impl<T, const M: usize, const N: usize> Matrix<T, M, N> {
         let mut random_matrix_data: Vec<Vector<T, N>> = Vec::with_capacity(M);
 
         for _ in 0..M {
-            random_matrix_data.push(Vector::new_random())
+            random_matrix_data.push(Vector::new_random_with(rng))
+        }
+
+        Matrix {
+            inner: Storage::from_rows(random_matrix_data),
         }
+    }
+
+    /// Matrix with every item drawn from `distribution` in row-major order
+    /// ```rust
+    /// # use sickmath::*;
+    /// let mut rng = rand::thread_rng();
+    /// let bernoulli = Bernoulli::new(0.8).unwrap();
+    /// let dropout_mask: Matrix<f32, 4, 16> = Matrix::from_distribution(&bernoulli, &mut rng);
+    ///
+    /// assert!(dropout_mask.iter().flatten().all(|num| *num == 0.0 || *num == 1.0));
+    /// ```
+    pub fn from_distribution(
+        distribution: &impl Distribution<T>,
+        rng: &mut impl Rng,
+    ) -> Matrix<T, M, N>
+    where
+        T: Default + FromPrimitive + Debug + Copy,
+    {
+        (0..M)
+            .map(|_| Vector::from_distribution(distribution, rng))
+            .collect()
+    }
+
+    /// Random matrix that is the same for every run with the same `seed`
+    /// ```rust
+    /// # use sickmath::*;
+    /// let weights: Matrix<f64, 3, 2> = Matrix::from_seed(1234);
+    /// assert_eq!(weights, Matrix::from_seed(1234));
+    /// assert_ne!(weights, Matrix::from_seed(4321));
+    /// ```
+    pub fn from_seed(seed: u64) -> Matrix<T, M, N>
+    where
+        T: Default + FromPrimitive + Debug + Copy,
+        Standard: Distribution<T>,
+    {
+        Matrix::new_random_with(&mut seeded_rng(seed))
+    }
 
+    /// Builds the matrix from `M * N` row-major items. Panics if there are more or fewer
+    pub(crate) fn from_row_major(items: &[T]) -> Matrix<T, M, N>
+    where
+        T: Default + Copy,
+    {
         Matrix {
-            inner: random_matrix_data.into_2d_vector(),
+            inner: Storage::from_row_major(items),
+        }
+    }
+
+    /// Copies the matrix into a row-major `Vec`
+    pub(crate) fn to_row_major(&self) -> Vec<T>
+    where
+        T: Copy,
+    {
+        let mut data = Vec::with_capacity(M * N);
+
+        for row in self.iter() {
+            for num in row.iter() {
+                data.push(*num);
+            }
+        }
+
+        data
+    }
+}
+
+impl<T, const N: usize> Matrix<T, N, N>
+where
+    T: Default + Clone + FromPrimitive + Copy + Debug + One,
+{
+    /// Square matrix with ones on the diagonal and zeros elsewhere
+    pub fn identity() -> Matrix<T, N, N> {
+        let mut identity: Matrix<T, N, N> = Matrix::default();
+
+        for idx in 0..N {
+            identity[idx][idx] = T::one();
         }
+
+        identity
     }
 }
 
@@ -44,9 +160,7 @@ where
     T: Default + Clone + FromPrimitive + Copy + Debug,
 {
     fn default() -> Matrix<T, M, N> {
-        Matrix {
-            inner: vec![Vector::default(); M].into_2d_vector(),
-        }
+        Matrix::from_row_major(&vec![T::default(); M * N])
     }
 }
 
@@ -57,3 +171,9 @@ impl<T, const M: usize, const N: usize> Index<usize> for Matrix<T, M, N> {
         &self.inner[idx]
     }
 }
+
+impl<T, const M: usize, const N: usize> IndexMut<usize> for Matrix<T, M, N> {
+    fn index_mut(&mut self, idx: usize) -> &mut Self::Output {
+        &mut self.inner[idx]
+    }
+}
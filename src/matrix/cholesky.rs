@@ -0,0 +1,222 @@
+use crate::{error::Error, vector::Vector};
+use num::{Float, FromPrimitive, ToPrimitive};
+use std::{
+    fmt::Debug,
+    ops::{Add, AddAssign, Mul, MulAssign, Sub, SubAssign},
+};
+
+use super::Matrix;
+
+/// Cholesky factorization `A = LLᵀ` of a symmetric positive definite matrix, where `L` is lower
+/// triangular.
+///
+/// ```rust
+/// # use sickmath::*;
+/// let matrix: Matrix<f64, 2, 2> = Matrix::new([[4.0, 2.0], [2.0, 2.0]]);
+/// let cholesky = matrix.cholesky().unwrap();
+///
+/// assert_eq!(cholesky.l, Matrix::new([[2.0, 0.0], [1.0, 1.0]]));
+/// assert_eq!(cholesky.solve(&Vector::new([6.0, 4.0])), Vector::new([1.0, 1.0]));
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct Cholesky<T, const N: usize> {
+    pub l: Matrix<T, N, N>,
+}
+
+impl<T, const N: usize> Matrix<T, N, N>
+where
+    T: Default
+        + Copy
+        + FromPrimitive
+        + ToPrimitive
+        + Mul<Output = T>
+        + MulAssign
+        + Add<Output = T>
+        + AddAssign
+        + Sub<Output = T>
+        + SubAssign
+        + Debug
+        + Float,
+{
+    /// Cholesky factorization of a symmetric positive definite matrix. Returns an error if the
+    /// matrix is not symmetric positive definite
+    pub fn cholesky(&self) -> Result<Cholesky<T, N>, Error> {
+        let mut largest = T::zero();
+        for row in self.iter() {
+            for num in row.iter() {
+                largest = largest.max(num.abs());
+            }
+        }
+        let tolerance = largest * T::epsilon() * T::from_usize(N).unwrap_or_else(T::one);
+
+        let mut l: Matrix<T, N, N> = Matrix::default();
+
+        for row in 0..N {
+            for col in 0..=row {
+                if (self[row][col] - self[col][row]).abs() > tolerance {
+                    return Err(Error::NotPositiveDefinite);
+                }
+
+                let mut acc = self[row][col];
+                for idx in 0..col {
+                    acc -= l[row][idx] * l[col][idx];
+                }
+
+                if row == col {
+                    if acc <= T::zero() || acc.is_nan() {
+                        return Err(Error::NotPositiveDefinite);
+                    }
+                    l[row][col] = acc.sqrt();
+                } else {
+                    l[row][col] = acc / l[col][col];
+                }
+            }
+        }
+
+        Ok(Cholesky { l })
+    }
+
+    /// Solves `Ax = b` for a symmetric positive definite matrix. Factorizes on every call, use
+    /// `cholesky` to solve several right hand sides
+    pub fn cholesky_solve(&self, b: &Vector<T, N>) -> Result<Vector<T, N>, Error> {
+        Ok(self.cholesky()?.solve(b))
+    }
+
+    /// Natural logarithm of the determinant of a symmetric positive definite matrix
+    pub fn log_determinant_spd(&self) -> Result<T, Error> {
+        Ok(self.cholesky()?.log_determinant())
+    }
+}
+
+impl<T, const N: usize> Cholesky<T, N>
+where
+    T: Default
+        + Copy
+        + FromPrimitive
+        + ToPrimitive
+        + Mul<Output = T>
+        + MulAssign
+        + Add<Output = T>
+        + AddAssign
+        + Sub<Output = T>
+        + SubAssign
+        + Debug
+        + Float,
+{
+    /// Solves `Ax = b` using forward substitution with `L` and back substitution with `Lᵀ`
+    pub fn solve(&self, b: &Vector<T, N>) -> Vector<T, N> {
+        let l = &self.l;
+
+        let mut x = b.clone();
+        for row in 0..N {
+            let mut acc = x[row];
+            for col in 0..row {
+                acc -= l[row][col] * x[col];
+            }
+            x[row] = acc / l[row][row];
+        }
+
+        for row in (0..N).rev() {
+            let mut acc = x[row];
+            for col in row + 1..N {
+                acc -= l[col][row] * x[col];
+            }
+            x[row] = acc / l[row][row];
+        }
+
+        x
+    }
+
+    /// Natural logarithm of the determinant, computed as `2 Σ ln(Lᵢᵢ)` to avoid the overflow of
+    /// multiplying the pivots
+    pub fn log_determinant(&self) -> T {
+        let mut acc = T::zero();
+        for idx in 0..N {
+            acc += self.l[idx][idx].ln();
+        }
+
+        acc + acc
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Error, Matrix, Vector};
+
+    #[test]
+    fn cholesky_reconstructs_matrix() {
+        let matrix: Matrix<f64, 3, 3> = Matrix::new([
+            [4.0, 12.0, -16.0],
+            [12.0, 37.0, -43.0],
+            [-16.0, -43.0, 98.0],
+        ]);
+
+        let l = matrix.cholesky().unwrap().l;
+
+        assert_eq!(
+            l,
+            Matrix::new([[2.0, 0.0, 0.0], [6.0, 1.0, 0.0], [-8.0, 5.0, 3.0]])
+        );
+        assert_eq!(l.mult(&l.transpose()), matrix);
+    }
+
+    #[test]
+    fn cholesky_not_positive_definite() {
+        let matrix: Matrix<f64, 2, 2> = Matrix::new([[1.0, 2.0], [2.0, 1.0]]);
+
+        assert_eq!(matrix.cholesky(), Err(Error::NotPositiveDefinite));
+    }
+
+    #[test]
+    fn cholesky_not_symmetric() {
+        let matrix: Matrix<f64, 2, 2> = Matrix::new([[4.0, 1.0], [2.0, 4.0]]);
+
+        assert_eq!(matrix.cholesky(), Err(Error::NotPositiveDefinite));
+    }
+
+    #[test]
+    fn cholesky_solve() {
+        let matrix: Matrix<f64, 3, 3> = Matrix::new([
+            [4.0, 12.0, -16.0],
+            [12.0, 37.0, -43.0],
+            [-16.0, -43.0, 98.0],
+        ]);
+
+        let b: Vector<f64, 3> = Vector::new([-4.0, -9.5, 33.0]);
+
+        let x = matrix.cholesky_solve(&b).unwrap();
+
+        assert!((x[0] - 1.0).abs() < 1e-10);
+        assert!((x[1] - 0.0).abs() < 1e-10);
+        assert!((x[2] - 0.5).abs() < 1e-10);
+    }
+
+    #[test]
+    fn log_determinant_spd() {
+        let matrix: Matrix<f64, 3, 3> = Matrix::new([
+            [4.0, 12.0, -16.0],
+            [12.0, 37.0, -43.0],
+            [-16.0, -43.0, 98.0],
+        ]);
+
+        let log_determinant = matrix.log_determinant_spd().unwrap();
+
+        assert!((log_determinant - 36.0_f64.ln()).abs() < 1e-10);
+    }
+
+    #[test]
+    fn reused_factorization() {
+        let matrix: Matrix<f64, 2, 2> = Matrix::new([[4.0, 2.0], [2.0, 2.0]]);
+        let cholesky = matrix.cholesky().unwrap();
+
+        for b in [Vector::new([6.0, 4.0]), Vector::new([2.0, 0.0])] {
+            let x = cholesky.solve(&b);
+            assert_eq!(x, matrix.cholesky_solve(&b).unwrap());
+            assert_eq!(matrix.mul_vector(&x), b);
+        }
+        assert_eq!(
+            cholesky.log_determinant(),
+            matrix.log_determinant_spd().unwrap()
+        );
+    }
+}
@@ -0,0 +1,590 @@
+use crate::{error::Error, vector::Vector};
+use num::{Complex, Float, FromPrimitive, ToPrimitive};
+use std::{
+    fmt::Debug,
+    ops::{Add, AddAssign, Mul, MulAssign, Sub, SubAssign},
+};
+
+use super::{jacobi, Matrix};
+
+/// Largest number of Jacobi sweeps before `symmetric_eigen` gives up
+const MAX_SWEEPS: usize = 64;
+
+/// Largest number of Francis steps spent on average per eigenvalue before `schur` gives up
+const MAX_STEPS_PER_EIGENVALUE: usize = 30;
+
+impl<T, const N: usize> Matrix<T, N, N>
+where
+    T: Default
+        + Copy
+        + FromPrimitive
+        + ToPrimitive
+        + Mul<Output = T>
+        + MulAssign
+        + Add<Output = T>
+        + AddAssign
+        + Sub<Output = T>
+        + SubAssign
+        + Debug
+        + Float,
+{
+    /// Eigendecomposition of a symmetric matrix using cyclic Jacobi rotations.
+    ///
+    /// Returns the eigenvalues in descending order together with a matrix whose orthonormal
+    /// columns are the matching eigenvectors. Returns an error if the matrix isn't symmetric.
+    /// ```rust
+    /// # use sickmath::*;
+    /// let matrix: Matrix<f64, 2, 2> = Matrix::new([[2.0, 1.0], [1.0, 2.0]]);
+    /// let (eigenvalues, eigenvectors) = matrix.symmetric_eigen().unwrap();
+    ///
+    /// assert!((eigenvalues[0] - 3.0).abs() < 1e-12);
+    /// assert!((eigenvalues[1] - 1.0).abs() < 1e-12);
+    /// assert!((eigenvectors[0][0].abs() - 0.5_f64.sqrt()).abs() < 1e-12);
+    /// ```
+    pub fn symmetric_eigen(&self) -> Result<(Vector<T, N>, Matrix<T, N, N>), Error> {
+        let mut frobenius = T::zero();
+        for row in self.iter() {
+            for num in row.iter() {
+                frobenius += *num * *num;
+            }
+        }
+        let tolerance = frobenius.sqrt() * T::epsilon();
+
+        for row in 0..N {
+            for col in row + 1..N {
+                if (self[row][col] - self[col][row]).abs() > tolerance {
+                    return Err(Error::NotSymmetric);
+                }
+            }
+        }
+
+        let mut a = self.clone();
+        let mut v: Matrix<T, N, N> = Matrix::identity();
+        let mut converged = false;
+
+        for _ in 0..MAX_SWEEPS {
+            let mut off_diagonal = T::zero();
+            for row in 0..N {
+                for col in row + 1..N {
+                    off_diagonal += a[row][col] * a[row][col];
+                }
+            }
+
+            if off_diagonal.sqrt() <= tolerance {
+                converged = true;
+                break;
+            }
+
+            for p in 0..N {
+                for q in p + 1..N {
+                    if a[p][q] == T::zero() {
+                        continue;
+                    }
+
+                    let (cos, sin) = jacobi::rotation(a[p][p], a[q][q], a[p][q]);
+
+                    for idx in 0..N {
+                        let (ap, aq) = (a[idx][p], a[idx][q]);
+                        a[idx][p] = cos * ap - sin * aq;
+                        a[idx][q] = sin * ap + cos * aq;
+                    }
+                    for idx in 0..N {
+                        let (ap, aq) = (a[p][idx], a[q][idx]);
+                        a[p][idx] = cos * ap - sin * aq;
+                        a[q][idx] = sin * ap + cos * aq;
+                    }
+                    for idx in 0..N {
+                        let (vp, vq) = (v[idx][p], v[idx][q]);
+                        v[idx][p] = cos * vp - sin * vq;
+                        v[idx][q] = sin * vp + cos * vq;
+                    }
+                }
+            }
+        }
+
+        if !converged {
+            return Err(Error::NotConverged {
+                iterations: MAX_SWEEPS,
+            });
+        }
+
+        let mut order: Vec<usize> = (0..N).collect();
+        order.sort_by(|a_idx, b_idx| {
+            a[*b_idx][*b_idx]
+                .partial_cmp(&a[*a_idx][*a_idx])
+                .unwrap_or(std::cmp::Ordering::Equal)
+        });
+
+        let mut eigenvalues: Vector<T, N> = Vector::default();
+        let mut eigenvectors: Matrix<T, N, N> = Matrix::default();
+        for (col, idx) in order.iter().enumerate() {
+            eigenvalues[col] = a[*idx][*idx];
+            for row in 0..N {
+                eigenvectors[row][col] = v[row][*idx];
+            }
+        }
+
+        Ok((eigenvalues, eigenvectors))
+    }
+}
+
+impl<T, const N: usize> Matrix<T, N, N>
+where
+    T: Default
+        + Copy
+        + FromPrimitive
+        + ToPrimitive
+        + Mul<Output = T>
+        + MulAssign
+        + Add<Output = T>
+        + AddAssign
+        + Sub<Output = T>
+        + SubAssign
+        + Debug
+        + Float,
+{
+    /// Reduces the matrix to upper Hessenberg form using Householder reflections.
+    ///
+    /// Returns an orthogonal `Q` and an upper Hessenberg `H` such that `A = QHQᵀ`.
+    pub fn hessenberg(&self) -> (Matrix<T, N, N>, Matrix<T, N, N>) {
+        let mut h = self.clone();
+        let mut q: Matrix<T, N, N> = Matrix::identity();
+
+        for col in 0..N.saturating_sub(2) {
+            let x: Vec<T> = (col + 1..N).map(|row| h[row][col]).collect();
+
+            if let Some((v, beta)) = householder(&x) {
+                reflect_rows(&mut h, &v, beta, col + 1, col..N);
+                reflect_columns(&mut h, &v, beta, col + 1, 0..N);
+                reflect_columns(&mut q, &v, beta, col + 1, 0..N);
+
+                for row in col + 2..N {
+                    h[row][col] = T::zero();
+                }
+            }
+        }
+
+        (q, h)
+    }
+
+    /// Real Schur decomposition using the implicitly double shifted QR algorithm on the
+    /// Hessenberg form of the matrix.
+    ///
+    /// Returns an orthogonal `Z` and a quasi upper triangular `T` such that `A = ZTZᵀ`. Every
+    /// real eigenvalue is on the diagonal of `T` and every pair of complex conjugate eigenvalues
+    /// is a 2x2 block on the diagonal. Returns an error if the iteration doesn't converge.
+    pub fn schur(&self) -> Result<(Matrix<T, N, N>, Matrix<T, N, N>), Error> {
+        let (mut z, mut t) = self.hessenberg();
+
+        let max_steps = MAX_STEPS_PER_EIGENVALUE * N;
+        let mut steps = 0;
+        let mut steps_since_deflation = 0;
+        let mut end = N;
+
+        while end > 1 {
+            let mut start = end - 1;
+            while start > 0 {
+                let scale = t[start - 1][start - 1].abs() + t[start][start].abs();
+                if t[start][start - 1].abs() <= T::epsilon() * scale {
+                    t[start][start - 1] = T::zero();
+                    break;
+                }
+                start -= 1;
+            }
+
+            if end - start <= 2 {
+                if end - start == 2 {
+                    split_block(&mut z, &mut t, start);
+                }
+
+                end = start;
+                steps_since_deflation = 0;
+                continue;
+            }
+
+            steps += 1;
+            steps_since_deflation += 1;
+            if steps > max_steps {
+                return Err(Error::NotConverged {
+                    iterations: max_steps,
+                });
+            }
+
+            francis_step(&mut z, &mut t, start, end, steps_since_deflation);
+        }
+
+        Ok((z, t))
+    }
+
+    /// Eigenvalues of a general square matrix computed from its real Schur form. Complex
+    /// eigenvalues come in conjugate pairs with the positive imaginary part first
+    /// ```rust
+    /// # use sickmath::*;
+    /// let rotation: Matrix<f64, 2, 2> = Matrix::new([[0.0, -1.0], [1.0, 0.0]]);
+    /// let eigenvalues = rotation.eigenvalues().unwrap();
+    ///
+    /// assert!((eigenvalues[0].im - 1.0).abs() < 1e-12);
+    /// assert!((eigenvalues[1].im + 1.0).abs() < 1e-12);
+    /// ```
+    pub fn eigenvalues(&self) -> Result<Vector<Complex<T>, N>, Error> {
+        let (_, t) = self.schur()?;
+
+        let mut eigenvalues: Vector<Complex<T>, N> = Vector::default();
+        let mut idx = 0;
+        while idx < N {
+            if idx + 1 < N && t[idx + 1][idx] != T::zero() {
+                let (a, b, c, d) = (
+                    t[idx][idx],
+                    t[idx][idx + 1],
+                    t[idx + 1][idx],
+                    t[idx + 1][idx + 1],
+                );
+                let two = T::one() + T::one();
+                let mean = (a + d) / two;
+                let imaginary = (-((a - d) * (a - d) / (two * two) + b * c)).sqrt();
+
+                eigenvalues[idx] = Complex::new(mean, imaginary);
+                eigenvalues[idx + 1] = Complex::new(mean, -imaginary);
+                idx += 2;
+            } else {
+                eigenvalues[idx] = Complex::new(t[idx][idx], T::zero());
+                idx += 1;
+            }
+        }
+
+        Ok(eigenvalues)
+    }
+}
+
+/// Householder vector `v` and `beta` such that `(I - beta * v * vᵀ) x` is a multiple of the
+/// first unit vector. Returns `None` if `x` is already zero
+fn householder<T: Float>(x: &[T]) -> Option<(Vec<T>, T)> {
+    let norm = x
+        .iter()
+        .fold(T::zero(), |acc, num| acc + *num * *num)
+        .sqrt();
+    if norm == T::zero() {
+        return None;
+    }
+
+    let alpha = if x[0] > T::zero() { -norm } else { norm };
+    let mut v = x.to_vec();
+    v[0] = v[0] - alpha;
+
+    let squared_norm = v.iter().fold(T::zero(), |acc, num| acc + *num * *num);
+    if squared_norm == T::zero() {
+        return None;
+    }
+
+    Some((v, (T::one() + T::one()) / squared_norm))
+}
+
+/// Applies a Householder reflection from the left to the rows starting at `offset`
+fn reflect_rows<T, const N: usize>(
+    matrix: &mut Matrix<T, N, N>,
+    v: &[T],
+    beta: T,
+    offset: usize,
+    cols: std::ops::Range<usize>,
+) where
+    T: Default + FromPrimitive + SubAssign + Debug + Float,
+{
+    for col in cols {
+        let mut acc = T::zero();
+        for (idx, num) in v.iter().enumerate() {
+            acc = acc + *num * matrix[offset + idx][col];
+        }
+
+        let scaled = acc * beta;
+        for (idx, num) in v.iter().enumerate() {
+            matrix[offset + idx][col] -= scaled * *num;
+        }
+    }
+}
+
+/// Applies a Householder reflection from the right to the columns starting at `offset`
+fn reflect_columns<T, const N: usize>(
+    matrix: &mut Matrix<T, N, N>,
+    v: &[T],
+    beta: T,
+    offset: usize,
+    rows: std::ops::Range<usize>,
+) where
+    T: Default + FromPrimitive + SubAssign + Debug + Float,
+{
+    for row in rows {
+        let mut acc = T::zero();
+        for (idx, num) in v.iter().enumerate() {
+            acc = acc + *num * matrix[row][offset + idx];
+        }
+
+        let scaled = acc * beta;
+        for (idx, num) in v.iter().enumerate() {
+            matrix[row][offset + idx] -= scaled * *num;
+        }
+    }
+}
+
+/// One implicitly double shifted QR step on the unreduced Hessenberg block `start..end`
+fn francis_step<T, const N: usize>(
+    z: &mut Matrix<T, N, N>,
+    t: &mut Matrix<T, N, N>,
+    start: usize,
+    end: usize,
+    steps_since_deflation: usize,
+) where
+    T: Default + FromPrimitive + SubAssign + Debug + Float,
+{
+    let last = end - 1;
+
+    // The shifts are the eigenvalues of the trailing 2x2 block, replaced by an exceptional shift
+    // after ten and twenty steps without deflation to break cycles
+    let (sum, product) = if steps_since_deflation == 10 || steps_since_deflation == 20 {
+        let exceptional = t[last][last - 1].abs() + t[last - 1][last - 2].abs();
+        let sum = exceptional * T::from_f64(1.5).unwrap_or_else(T::one);
+
+        (sum, exceptional * exceptional)
+    } else {
+        (
+            t[last - 1][last - 1] + t[last][last],
+            t[last - 1][last - 1] * t[last][last] - t[last - 1][last] * t[last][last - 1],
+        )
+    };
+
+    let mut x = t[start][start] * t[start][start] + t[start][start + 1] * t[start + 1][start]
+        - sum * t[start][start]
+        + product;
+    let mut y = t[start + 1][start] * (t[start][start] + t[start + 1][start + 1] - sum);
+    let mut w = t[start + 1][start] * t[start + 2][start + 1];
+
+    for col in start..last - 1 {
+        if let Some((v, beta)) = householder(&[x, y, w]) {
+            reflect_rows(t, &v, beta, col, col.max(start + 1) - 1..N);
+            reflect_columns(t, &v, beta, col, 0..(col + 4).min(end));
+            reflect_columns(z, &v, beta, col, 0..N);
+
+            // The reflection chased the bulge one column further, leaving exact zeros behind
+            if col > start {
+                t[col + 1][col - 1] = T::zero();
+                t[col + 2][col - 1] = T::zero();
+            }
+        }
+
+        x = t[col + 1][col];
+        y = t[col + 2][col];
+        if col + 3 < end {
+            w = t[col + 3][col];
+        }
+    }
+
+    if let Some((v, beta)) = householder(&[x, y]) {
+        reflect_rows(t, &v, beta, last - 1, last - 2..N);
+        reflect_columns(t, &v, beta, last - 1, 0..end);
+        reflect_columns(z, &v, beta, last - 1, 0..N);
+
+        t[last][last - 2] = T::zero();
+    }
+}
+
+/// Triangularizes a deflated 2x2 block starting at `idx` if its eigenvalues are real
+fn split_block<T, const N: usize>(z: &mut Matrix<T, N, N>, t: &mut Matrix<T, N, N>, idx: usize)
+where
+    T: Default + FromPrimitive + Debug + Float,
+{
+    let (a, b, c, d) = (
+        t[idx][idx],
+        t[idx][idx + 1],
+        t[idx + 1][idx],
+        t[idx + 1][idx + 1],
+    );
+    let two = T::one() + T::one();
+    let half_difference = (a - d) / two;
+    let discriminant = half_difference * half_difference + b * c;
+
+    if discriminant < T::zero() {
+        return;
+    }
+
+    // Rotate the eigenvector of the eigenvalue furthest from `d` onto the first axis
+    let root = discriminant.sqrt();
+    let eigenvalue = if half_difference >= T::zero() {
+        d + half_difference + root
+    } else {
+        d + half_difference - root
+    };
+
+    let (x, y) = if (eigenvalue - d).abs() >= b.abs() {
+        (eigenvalue - d, c)
+    } else {
+        (b, eigenvalue - a)
+    };
+    let norm = (x * x + y * y).sqrt();
+    if norm == T::zero() {
+        return;
+    }
+    let (cos, sin) = (x / norm, y / norm);
+
+    for col in idx..N {
+        let (upper, lower) = (t[idx][col], t[idx + 1][col]);
+        t[idx][col] = cos * upper + sin * lower;
+        t[idx + 1][col] = cos * lower - sin * upper;
+    }
+    for row in 0..idx + 2 {
+        let (left, right) = (t[row][idx], t[row][idx + 1]);
+        t[row][idx] = cos * left + sin * right;
+        t[row][idx + 1] = cos * right - sin * left;
+    }
+    for row in 0..N {
+        let (left, right) = (z[row][idx], z[row][idx + 1]);
+        z[row][idx] = cos * left + sin * right;
+        z[row][idx + 1] = cos * right - sin * left;
+    }
+
+    t[idx + 1][idx] = T::zero();
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Error, Matrix, Vector};
+
+    fn assert_close<const M: usize, const N: usize>(a: &Matrix<f64, M, N>, b: &Matrix<f64, M, N>) {
+        for row in 0..M {
+            for col in 0..N {
+                assert!(
+                    (a[row][col] - b[row][col]).abs() < 1e-10,
+                    "{:?} is not close to {:?}",
+                    a,
+                    b
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn symmetric_eigen_decomposes_matrix() {
+        let matrix: Matrix<f64, 3, 3> =
+            Matrix::new([[4.0, 1.0, -2.0], [1.0, 2.0, 0.0], [-2.0, 0.0, 3.0]]);
+
+        let (eigenvalues, eigenvectors) = matrix.symmetric_eigen().unwrap();
+
+        let mut diagonal: Matrix<f64, 3, 3> = Matrix::default();
+        for idx in 0..3 {
+            diagonal[idx][idx] = eigenvalues[idx];
+        }
+
+        assert!(eigenvalues[0] >= eigenvalues[1] && eigenvalues[1] >= eigenvalues[2]);
+        assert_close(
+            &eigenvectors.transpose().mult(&eigenvectors),
+            &Matrix::identity(),
+        );
+        assert_close(
+            &eigenvectors.mult(&diagonal).mult(&eigenvectors.transpose()),
+            &matrix,
+        );
+    }
+
+    #[test]
+    fn symmetric_eigen_diagonal_matrix() {
+        let matrix: Matrix<f64, 3, 3> =
+            Matrix::new([[1.0, 0.0, 0.0], [0.0, 3.0, 0.0], [0.0, 0.0, 2.0]]);
+
+        let (eigenvalues, eigenvectors) = matrix.symmetric_eigen().unwrap();
+
+        assert_eq!(eigenvalues, Vector::new([3.0, 2.0, 1.0]));
+        assert_close(
+            &eigenvectors,
+            &Matrix::new([[0.0, 0.0, 1.0], [1.0, 0.0, 0.0], [0.0, 1.0, 0.0]]),
+        );
+    }
+
+    #[test]
+    fn hessenberg_reconstructs_matrix() {
+        let matrix: Matrix<f64, 4, 4> = Matrix::new([
+            [4.0, 1.0, -2.0, 2.0],
+            [1.0, 2.0, 0.0, 1.0],
+            [-2.0, 0.0, 3.0, -2.0],
+            [2.0, 1.0, -2.0, -1.0],
+        ]);
+
+        let (q, h) = matrix.hessenberg();
+
+        assert_eq!(h[2][0], 0.0);
+        assert_eq!(h[3][0], 0.0);
+        assert_eq!(h[3][1], 0.0);
+        assert_close(&q.mult(&h).mult(&q.transpose()), &matrix);
+    }
+
+    #[test]
+    fn schur_reconstructs_matrix() {
+        let matrix: Matrix<f64, 4, 4> = Matrix::new([
+            [1.0, 2.0, 3.0, 4.0],
+            [-2.0, 1.0, 0.5, 1.0],
+            [0.0, 3.0, -1.0, 2.0],
+            [1.0, 0.0, 1.0, 2.0],
+        ]);
+
+        let (z, t) = matrix.schur().unwrap();
+
+        assert_close(&z.transpose().mult(&z), &Matrix::identity());
+        assert_close(&z.mult(&t).mult(&z.transpose()), &matrix);
+        for row in 2..4 {
+            for col in 0..row - 1 {
+                assert_eq!(t[row][col], 0.0);
+            }
+        }
+    }
+
+    #[test]
+    fn eigenvalues_of_real_matrix() {
+        let matrix: Matrix<f64, 3, 3> =
+            Matrix::new([[2.0, 0.0, 0.0], [1.0, 3.0, 0.0], [4.0, 5.0, 6.0]]);
+
+        let mut eigenvalues: Vec<f64> = matrix
+            .eigenvalues()
+            .unwrap()
+            .iter()
+            .map(|eigenvalue| {
+                assert!(eigenvalue.im.abs() < 1e-10);
+                eigenvalue.re
+            })
+            .collect();
+        eigenvalues.sort_by(|a, b| a.partial_cmp(b).unwrap());
+
+        for (eigenvalue, expected) in eigenvalues.iter().zip([2.0, 3.0, 6.0].iter()) {
+            assert!((eigenvalue - expected).abs() < 1e-10);
+        }
+    }
+
+    #[test]
+    fn eigenvalues_with_complex_pair() {
+        // Characteristic polynomial (λ - 2)(λ² - 2λ + 5) with roots 2 and 1 ± 2i
+        let matrix: Matrix<f64, 3, 3> =
+            Matrix::new([[1.0, -2.0, 0.0], [2.0, 1.0, 0.0], [0.0, 0.0, 2.0]]);
+        let (q, _) = Matrix::new([[1.0, 2.0, 0.5], [0.0, 1.0, 3.0], [1.0, 0.0, 1.0]]).qr();
+        let similar = q.transpose().mult(&matrix).mult(&q);
+
+        let eigenvalues = similar.eigenvalues().unwrap();
+
+        let mut real = 0;
+        let mut complex = 0;
+        for eigenvalue in eigenvalues.iter() {
+            if eigenvalue.im.abs() < 1e-10 {
+                assert!((eigenvalue.re - 2.0).abs() < 1e-10);
+                real += 1;
+            } else {
+                assert!((eigenvalue.re - 1.0).abs() < 1e-10);
+                assert!((eigenvalue.im.abs() - 2.0).abs() < 1e-10);
+                complex += 1;
+            }
+        }
+
+        assert_eq!((real, complex), (1, 2));
+    }
+
+    #[test]
+    fn symmetric_eigen_not_symmetric() {
+        let matrix: Matrix<f64, 2, 2> = Matrix::new([[1.0, 2.0], [0.0, 1.0]]);
+
+        assert_eq!(matrix.symmetric_eigen().err(), Some(Error::NotSymmetric));
+    }
+}
@@ -0,0 +1,179 @@
+use std::ops::{AddAssign, Mul};
+
+/// Rows of `A` handled by one call of the micro-kernel
+const MR: usize = 4;
+/// Columns of `B` handled by one call of the micro-kernel
+const NR: usize = 8;
+/// Depth of the packed panels, sized so a panel of `B` stays in L1
+const KC: usize = 256;
+/// Rows of the packed block of `A`, sized so the block stays in L2
+const MC: usize = 64;
+/// Columns of the packed block of `B`, sized so the block stays in L3
+const NC: usize = 2048;
+
+/// Cache blocked matrix product `C += AB` of an `m` x `k` matrix `A` and a `k` x `n` matrix `B`.
+///
+/// `a` and `b` return a row of their operand as a slice, which lets the caller use any storage
+/// that keeps rows contiguous. Blocks of both operands are packed into contiguous panels before
+/// being handed to a register blocked micro-kernel, so `C` only needs to be a row-major slice of
+/// length `m * n`.
+pub(crate) fn gemm<'a, T>(
+    m: usize,
+    n: usize,
+    k: usize,
+    a: impl Fn(usize) -> &'a [T],
+    b: impl Fn(usize) -> &'a [T],
+    c: &mut [T],
+) where
+    T: 'a,
+    T: Copy + Default + Mul<Output = T> + AddAssign,
+{
+    let mut a_pack: Vec<T> = vec![T::default(); MC.min(m.next_multiple_of(MR)) * KC.min(k)];
+    let mut b_pack: Vec<T> = vec![T::default(); NC.min(n.next_multiple_of(NR)) * KC.min(k)];
+
+    for jc in (0..n).step_by(NC) {
+        let nc = NC.min(n - jc);
+
+        for pc in (0..k).step_by(KC) {
+            let kc = KC.min(k - pc);
+            pack_b(&b, &mut b_pack, pc, jc, kc, nc);
+
+            for ic in (0..m).step_by(MC) {
+                let mc = MC.min(m - ic);
+                pack_a(&a, &mut a_pack, ic, pc, mc, kc);
+
+                for jr in (0..nc).step_by(NR) {
+                    let b_panel = &b_pack[jr * kc..(jr + NR) * kc];
+
+                    for ir in (0..mc).step_by(MR) {
+                        let a_panel = &a_pack[ir * kc..(ir + MR) * kc];
+                        let acc = micro_kernel(a_panel, b_panel, kc);
+
+                        for (row, acc_row) in acc.iter().enumerate().take(MR.min(mc - ir)) {
+                            let offset = (ic + ir + row) * n + jc + jr;
+                            let c_row = &mut c[offset..offset + NR.min(nc - jr)];
+
+                            for (num, acc_num) in c_row.iter_mut().zip(acc_row.iter()) {
+                                *num += *acc_num;
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// Multiplies an `MR` x `kc` panel of `A` with a `kc` x `NR` panel of `B` held in registers
+#[inline(always)]
+fn micro_kernel<T>(a_panel: &[T], b_panel: &[T], kc: usize) -> [[T; NR]; MR]
+where
+    T: Copy + Default + Mul<Output = T> + AddAssign,
+{
+    let mut acc = [[T::default(); NR]; MR];
+
+    for (a_col, b_row) in a_panel
+        .chunks_exact(MR)
+        .zip(b_panel.chunks_exact(NR))
+        .take(kc)
+    {
+        for (acc_row, a_num) in acc.iter_mut().zip(a_col.iter()) {
+            for (acc_num, b_num) in acc_row.iter_mut().zip(b_row.iter()) {
+                *acc_num += *a_num * *b_num;
+            }
+        }
+    }
+
+    acc
+}
+
+/// Packs an `mc` x `kc` block of `A` into column-major panels of `MR` rows, padded with zeros
+fn pack_a<'a, T>(
+    a: &impl Fn(usize) -> &'a [T],
+    a_pack: &mut [T],
+    ic: usize,
+    pc: usize,
+    mc: usize,
+    kc: usize,
+) where
+    T: 'a + Copy + Default,
+{
+    for ir in (0..mc).step_by(MR) {
+        let panel = &mut a_pack[ir * kc..(ir + MR) * kc];
+
+        for row in 0..MR {
+            if ir + row < mc {
+                let a_row = &a(ic + ir + row)[pc..pc + kc];
+                for (column, num) in panel.chunks_exact_mut(MR).zip(a_row.iter()) {
+                    column[row] = *num;
+                }
+            } else {
+                for column in panel.chunks_exact_mut(MR) {
+                    column[row] = T::default();
+                }
+            }
+        }
+    }
+}
+
+/// Packs a `kc` x `nc` block of `B` into row-major panels of `NR` columns, padded with zeros
+fn pack_b<'a, T>(
+    b: &impl Fn(usize) -> &'a [T],
+    b_pack: &mut [T],
+    pc: usize,
+    jc: usize,
+    kc: usize,
+    nc: usize,
+) where
+    T: 'a + Copy + Default,
+{
+    for p in 0..kc {
+        let b_row = &b(pc + p)[jc..jc + nc];
+
+        for (panel, cols) in b_pack.chunks_exact_mut(NR * kc).zip(b_row.chunks(NR)) {
+            let panel_row = &mut panel[p * NR..(p + 1) * NR];
+            let (filled, padding) = panel_row.split_at_mut(cols.len());
+
+            filled.copy_from_slice(cols);
+            padding.fill(T::default());
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn naive(m: usize, n: usize, k: usize, a: &[i64], b: &[i64]) -> Vec<i64> {
+        let mut c = vec![0; m * n];
+        for row in 0..m {
+            for col in 0..n {
+                for idx in 0..k {
+                    c[row * n + col] += a[row * k + idx] * b[idx * n + col];
+                }
+            }
+        }
+
+        c
+    }
+
+    #[test]
+    fn gemm_matches_naive_product() {
+        // Dimensions that aren't multiples of any block size exercise the padding
+        let (m, n, k) = (70, 19, 300);
+        let a: Vec<i64> = (0..m * k).map(|idx| (idx % 7) as i64 - 3).collect();
+        let b: Vec<i64> = (0..k * n).map(|idx| (idx % 5) as i64 - 2).collect();
+
+        let mut c = vec![0; m * n];
+        gemm(
+            m,
+            n,
+            k,
+            |row| &a[row * k..(row + 1) * k],
+            |row| &b[row * n..(row + 1) * n],
+            &mut c,
+        );
+
+        assert_eq!(c, naive(m, n, k, &a, &b));
+    }
+}
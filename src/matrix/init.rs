@@ -0,0 +1,98 @@
+use std::fmt::Debug;
+
+use num::{Float, FromPrimitive};
+use rand::{
+    distributions::{uniform::SampleUniform, Uniform},
+    Rng,
+};
+
+use super::Matrix;
+use crate::random::Normal;
+
+/// Weight initializers for a layer computing `Wx`, so `N` is the fan-in and `M` the fan-out
+impl<T, const M: usize, const N: usize> Matrix<T, M, N>
+where
+    T: Default + Copy + FromPrimitive + Debug + Float + SampleUniform,
+{
+    /// Uniform on `[-a, a)` with `a = sqrt(6 / (N + M))`
+    /// ```rust
+    /// # use sickmath::*;
+    /// let weights: Matrix<f64, 4, 8> = Matrix::xavier_uniform(&mut rand::thread_rng());
+    /// let limit = (6.0_f64 / 12.0).sqrt();
+    ///
+    /// assert!(weights.iter().flatten().all(|num| num.abs() <= limit));
+    /// ```
+    pub fn xavier_uniform(rng: &mut impl Rng) -> Matrix<T, M, N> {
+        let limit = Self::scale(6.0 / (N + M) as f64);
+
+        Matrix::from_distribution(&Uniform::new(-limit, limit), rng)
+    }
+
+    /// Normal with mean zero and standard deviation `sqrt(2 / (N + M))`
+    pub fn xavier_normal(rng: &mut impl Rng) -> Matrix<T, M, N> {
+        Self::zero_mean_normal(2.0 / (N + M) as f64, rng)
+    }
+
+    /// Normal with mean zero and standard deviation `sqrt(2 / N)`, suited to ReLU layers
+    pub fn he_normal(rng: &mut impl Rng) -> Matrix<T, M, N> {
+        Self::zero_mean_normal(2.0 / N as f64, rng)
+    }
+
+    /// Normal with mean zero and standard deviation `sqrt(1 / N)`, suited to SELU layers
+    pub fn lecun_normal(rng: &mut impl Rng) -> Matrix<T, M, N> {
+        Self::zero_mean_normal(1.0 / N as f64, rng)
+    }
+
+    fn zero_mean_normal(variance: f64, rng: &mut impl Rng) -> Matrix<T, M, N> {
+        let normal = Normal::new(T::zero(), Self::scale(variance)).unwrap();
+
+        Matrix::from_distribution(&normal, rng)
+    }
+
+    /// `sqrt(variance)` as `T`
+    fn scale(variance: f64) -> T {
+        T::from_f64(variance.sqrt()).unwrap()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{random::seeded_rng, Matrix};
+
+    fn std_dev<const M: usize, const N: usize>(matrix: &Matrix<f64, M, N>) -> f64 {
+        let count = (M * N) as f64;
+        let mean = matrix.iter().flatten().sum::<f64>() / count;
+
+        (matrix
+            .iter()
+            .flatten()
+            .map(|num| (num - mean).powi(2))
+            .sum::<f64>()
+            / count)
+            .sqrt()
+    }
+
+    #[test]
+    fn normal_initializer_scales() {
+        let mut rng = seeded_rng(17);
+
+        let xavier: Matrix<f64, 100, 300> = Matrix::xavier_normal(&mut rng);
+        let he: Matrix<f64, 100, 300> = Matrix::he_normal(&mut rng);
+        let lecun: Matrix<f64, 100, 300> = Matrix::lecun_normal(&mut rng);
+
+        assert!((std_dev(&xavier) - (2.0_f64 / 400.0).sqrt()).abs() < 1e-3);
+        assert!((std_dev(&he) - (2.0_f64 / 300.0).sqrt()).abs() < 1e-3);
+        assert!((std_dev(&lecun) - (1.0_f64 / 300.0).sqrt()).abs() < 1e-3);
+    }
+
+    #[test]
+    fn xavier_uniform_bounds() {
+        let weights: Matrix<f32, 50, 30> = Matrix::xavier_uniform(&mut seeded_rng(2));
+        let limit = (6.0_f32 / 80.0).sqrt();
+
+        assert!(weights.iter().flatten().all(|num| num.abs() <= limit));
+        // The variance of U(-a, a) is a² / 3 = 2 / (N + M)
+        let variance = weights.iter().flatten().map(|num| num * num).sum::<f32>() / 1500.0;
+        assert!((variance - 2.0 / 80.0).abs() < 2e-3, "{}", variance);
+    }
+}
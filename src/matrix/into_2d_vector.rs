@@ -1,14 +1,43 @@
-use crate::vector::Vector;
+use crate::{error::Error, vector::Vector};
 use core::fmt;
 use num::FromPrimitive;
+use std::convert::TryInto;
 
 pub trait Into2dVector<T, const M: usize, const N: usize> {
     fn into_2d_vector(self) -> [Vector<T, N>; M];
+
+    /// Same as `into_2d_vector` but returns an error if there aren't exactly `M` rows of length `N`
+    fn try_into_2d_vector(self) -> Result<[Vector<T, N>; M], Error>;
+
+    /// Converts the rows into a `Vec`, which doesn't need the whole matrix to fit on the stack.
+    /// Returns an error if a row doesn't have a length of `N`, the number of rows is checked by
+    /// the caller
+    fn try_into_rows(self) -> Result<Vec<Vector<T, N>>, Error>;
 }
+
+/// Collects already converted rows into the array of a `Matrix`
+fn collect_rows<T, const M: usize, const N: usize>(
+    rows: Vec<Vector<T, N>>,
+) -> Result<[Vector<T, N>; M], Error> {
+    rows.try_into()
+        .map_err(|rows: Vec<Vector<T, N>>| Error::LengthMismatch {
+            expected: M,
+            found: rows.len(),
+        })
+}
+
 impl<T, const M: usize, const N: usize> Into2dVector<T, M, N> for [Vector<T, N>; M] {
     fn into_2d_vector(self) -> [Vector<T, N>; M] {
         self
     }
+
+    fn try_into_2d_vector(self) -> Result<[Vector<T, N>; M], Error> {
+        Ok(self)
+    }
+
+    fn try_into_rows(self) -> Result<Vec<Vector<T, N>>, Error> {
+        Ok(Vec::from(self))
+    }
 }
 
 impl<T, const M: usize, const N: usize> Into2dVector<T, M, N> for Vec<Vec<T>>
@@ -16,13 +45,16 @@ where
     T: Default + fmt::Debug + FromPrimitive + Copy,
 {
     fn into_2d_vector(self) -> [Vector<T, N>; M] {
-        let mut array_of_vectors: [Vector<T, N>; M] = unsafe { std::mem::zeroed() };
+        self.try_into_2d_vector()
+            .unwrap_or_else(|err: Error| panic!("{}", err))
+    }
 
-        for (idx, container) in array_of_vectors.iter_mut().enumerate() {
-            *container = Vector::new(self[idx].clone());
-        }
+    fn try_into_2d_vector(self) -> Result<[Vector<T, N>; M], Error> {
+        collect_rows(Into2dVector::<T, M, N>::try_into_rows(self)?)
+    }
 
-        array_of_vectors
+    fn try_into_rows(self) -> Result<Vec<Vector<T, N>>, Error> {
+        self.into_iter().map(Vector::try_new).collect()
     }
 }
 
@@ -31,13 +63,16 @@ where
     T: Default + fmt::Debug + FromPrimitive + Copy,
 {
     fn into_2d_vector(self) -> [Vector<T, N>; M] {
-        let mut array_of_vectors: [Vector<T, N>; M] = unsafe { std::mem::zeroed() };
+        self.try_into_2d_vector()
+            .unwrap_or_else(|err: Error| panic!("{}", err))
+    }
 
-        for (idx, container) in array_of_vectors.iter_mut().enumerate() {
-            *container = Vector::new(self[idx]);
-        }
+    fn try_into_2d_vector(self) -> Result<[Vector<T, N>; M], Error> {
+        collect_rows(Into2dVector::<T, M, N>::try_into_rows(self)?)
+    }
 
-        array_of_vectors
+    fn try_into_rows(self) -> Result<Vec<Vector<T, N>>, Error> {
+        Ok(self.into_iter().map(Vector::new).collect())
     }
 }
 
@@ -45,15 +80,17 @@ impl<T, const M: usize, const N: usize> Into2dVector<T, M, N> for [[T; N]; M]
 where
     T: Default + fmt::Debug + FromPrimitive + Copy,
 {
-    fn into_2d_vector(self) -> [Vector<T, N>; M]
-where {
-        let mut array_of_vectors: [Vector<T, N>; M] = unsafe { std::mem::zeroed() };
+    fn into_2d_vector(self) -> [Vector<T, N>; M] {
+        self.try_into_2d_vector()
+            .unwrap_or_else(|err: Error| panic!("{}", err))
+    }
 
-        for (idx, container) in array_of_vectors.iter_mut().enumerate() {
-            *container = Vector::new(self[idx]);
-        }
+    fn try_into_2d_vector(self) -> Result<[Vector<T, N>; M], Error> {
+        collect_rows(Into2dVector::<T, M, N>::try_into_rows(self)?)
+    }
 
-        array_of_vectors
+    fn try_into_rows(self) -> Result<Vec<Vector<T, N>>, Error> {
+        Ok(self.iter().map(|row| Vector::new(*row)).collect())
     }
 }
 
@@ -62,13 +99,18 @@ where
     T: Default + fmt::Debug + FromPrimitive + Copy,
 {
     fn into_2d_vector(self) -> [Vector<T, N>; M] {
-        let mut array_of_vectors: [Vector<T, N>; M] = unsafe { std::mem::zeroed() };
+        self.try_into_2d_vector()
+            .unwrap_or_else(|err: Error| panic!("{}", err))
+    }
 
-        for (idx, vec) in array_of_vectors.iter_mut().enumerate() {
-            *vec = Vector::new(self[idx].clone());
-        }
+    fn try_into_2d_vector(self) -> Result<[Vector<T, N>; M], Error> {
+        collect_rows(Into2dVector::<T, M, N>::try_into_rows(self)?)
+    }
 
-        array_of_vectors
+    fn try_into_rows(self) -> Result<Vec<Vector<T, N>>, Error> {
+        self.iter()
+            .map(|row| Vector::try_new(row.clone()))
+            .collect()
     }
 }
 
@@ -77,12 +119,59 @@ where
     T: Default + fmt::Debug + FromPrimitive + Copy,
 {
     fn into_2d_vector(self) -> [Vector<T, N>; M] {
-        let mut array_of_vectors: [Vector<T, N>; M] = unsafe { std::mem::zeroed() };
+        self.try_into_2d_vector()
+            .unwrap_or_else(|err: Error| panic!("{}", err))
+    }
+
+    fn try_into_2d_vector(self) -> Result<[Vector<T, N>; M], Error> {
+        collect_rows(Into2dVector::<T, M, N>::try_into_rows(self)?)
+    }
+
+    fn try_into_rows(self) -> Result<Vec<Vector<T, N>>, Error> {
+        Ok(self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn into_2d_vector_from_vec_of_vecs() {
+        let data = vec![vec![1, 2], vec![3, 4]];
+
+        let rows: [Vector<u8, 2>; 2] = data.into_2d_vector();
+
+        assert_eq!(rows, [Vector::new([1, 2]), Vector::new([3, 4])]);
+    }
+
+    #[test]
+    fn try_into_2d_vector_wrong_row_count() {
+        let data = vec![[1, 2], [3, 4], [5, 6]];
+
+        let rows: Result<[Vector<u8, 2>; 2], Error> = data.try_into_2d_vector();
+
+        assert_eq!(
+            rows,
+            Err(Error::LengthMismatch {
+                expected: 2,
+                found: 3
+            })
+        );
+    }
+
+    #[test]
+    fn try_into_2d_vector_wrong_row_length() {
+        let data = vec![vec![1, 2], vec![3]];
 
-        for (idx, container) in array_of_vectors.iter_mut().enumerate() {
-            *container = self[idx].clone();
-        }
+        let rows: Result<[Vector<u8, 2>; 2], Error> = data.try_into_2d_vector();
 
-        array_of_vectors
+        assert_eq!(
+            rows,
+            Err(Error::LengthMismatch {
+                expected: 2,
+                found: 1
+            })
+        );
     }
 }
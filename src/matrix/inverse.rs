@@ -0,0 +1,187 @@
+use crate::error::Error;
+use num::{Float, FromPrimitive, ToPrimitive};
+use std::{
+    fmt::Debug,
+    ops::{Add, AddAssign, Mul, MulAssign, Sub, SubAssign},
+};
+
+use super::{jacobi, Matrix};
+
+impl<T, const N: usize> Matrix<T, N, N>
+where
+    T: Default
+        + Copy
+        + FromPrimitive
+        + ToPrimitive
+        + Mul<Output = T>
+        + MulAssign
+        + Add<Output = T>
+        + AddAssign
+        + Sub<Output = T>
+        + SubAssign
+        + Debug
+        + Float,
+{
+    /// Inverse of the matrix. Returns an error if the matrix is singular
+    /// ```rust
+    /// # use sickmath::*;
+    /// let matrix: Matrix<f64, 2, 2> = Matrix::new([[2.0, 1.0], [1.0, 1.0]]);
+    /// let inverse = matrix.inverse().unwrap();
+    ///
+    /// let expected: Matrix<f64, 2, 2> = Matrix::new([[1.0, -1.0], [-1.0, 2.0]]);
+    ///
+    /// for (row, expected_row) in inverse.iter().zip(expected.iter()) {
+    ///     for (num, expected_num) in row.iter().zip(expected_row.iter()) {
+    ///         assert!((num - expected_num).abs() < 1e-12);
+    ///     }
+    /// }
+    /// ```
+    pub fn inverse(&self) -> Result<Matrix<T, N, N>, Error> {
+        self.lu().solve_matrix(&Matrix::identity())
+    }
+}
+
+impl<T, const M: usize, const N: usize> Matrix<T, M, N>
+where
+    T: Default
+        + Copy
+        + FromPrimitive
+        + ToPrimitive
+        + Mul<Output = T>
+        + MulAssign
+        + Add<Output = T>
+        + AddAssign
+        + Sub<Output = T>
+        + SubAssign
+        + Debug
+        + Float,
+{
+    /// Moore–Penrose pseudo-inverse computed from the singular value decomposition.
+    ///
+    /// Singular values below `max(M, N) * ε * σ_max` are treated as zero.
+    pub fn pseudo_inverse(&self) -> Matrix<T, N, M> {
+        let (u, singular_values, v) = jacobi::svd(&self.to_row_major(), M, N);
+
+        let largest = singular_values.first().copied().unwrap_or_else(T::zero);
+        let tolerance = largest * T::epsilon() * T::from_usize(M.max(N)).unwrap_or_else(T::one);
+
+        pseudo_inverse_from_svd(&u, &singular_values, &v, tolerance)
+    }
+
+    /// Moore–Penrose pseudo-inverse where every singular value less than or equal to `tolerance`
+    /// is treated as zero
+    pub fn pseudo_inverse_with_tolerance(&self, tolerance: T) -> Matrix<T, N, M> {
+        let (u, singular_values, v) = jacobi::svd(&self.to_row_major(), M, N);
+
+        pseudo_inverse_from_svd(&u, &singular_values, &v, tolerance)
+    }
+}
+
+/// Computes `V Σ⁺ Uᵀ` from the row-major output of `jacobi::svd`
+fn pseudo_inverse_from_svd<T, const M: usize, const N: usize>(
+    u: &[T],
+    singular_values: &[T],
+    v: &[T],
+    tolerance: T,
+) -> Matrix<T, N, M>
+where
+    T: Default + Copy + FromPrimitive + AddAssign + Debug + Float,
+{
+    let rank_dim = singular_values.len();
+    let mut pseudo_inverse: Matrix<T, N, M> = Matrix::default();
+
+    for (idx, singular_value) in singular_values.iter().enumerate() {
+        if *singular_value <= tolerance {
+            continue;
+        }
+
+        for row in 0..N {
+            let scaled = v[row * rank_dim + idx] / *singular_value;
+            for col in 0..M {
+                pseudo_inverse[row][col] += scaled * u[col * rank_dim + idx];
+            }
+        }
+    }
+
+    pseudo_inverse
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Error, Matrix};
+
+    fn assert_close<const M: usize, const N: usize>(a: &Matrix<f64, M, N>, b: &Matrix<f64, M, N>) {
+        for row in 0..M {
+            for col in 0..N {
+                assert!(
+                    (a[row][col] - b[row][col]).abs() < 1e-10,
+                    "{:?} is not close to {:?}",
+                    a,
+                    b
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn inverse_matrix() {
+        let matrix: Matrix<f64, 3, 3> =
+            Matrix::new([[2.0, 0.0, 1.0], [1.0, 1.0, 0.0], [0.0, 3.0, 1.0]]);
+
+        let inverse = matrix.inverse().unwrap();
+
+        assert_close(&matrix.mult(&inverse), &Matrix::identity());
+    }
+
+    #[test]
+    fn inverse_singular_matrix() {
+        let matrix: Matrix<f64, 2, 2> = Matrix::new([[1.0, 2.0], [2.0, 4.0]]);
+
+        assert_eq!(matrix.inverse(), Err(Error::SingularMatrix));
+    }
+
+    #[test]
+    fn pseudo_inverse_of_invertible_matrix() {
+        let matrix: Matrix<f64, 2, 2> = Matrix::new([[4.0, 7.0], [2.0, 6.0]]);
+
+        assert_close(&matrix.pseudo_inverse(), &matrix.inverse().unwrap());
+    }
+
+    #[test]
+    fn pseudo_inverse_of_rectangular_matrix() {
+        let matrix: Matrix<f64, 3, 2> = Matrix::new([[1.0, 2.0], [3.0, 4.0], [5.0, 6.0]]);
+
+        let pseudo_inverse = matrix.pseudo_inverse();
+
+        assert_close(&matrix.mult(&pseudo_inverse).mult(&matrix), &matrix);
+        assert_close(
+            &pseudo_inverse,
+            &Matrix::new([
+                [-4.0 / 3.0, -1.0 / 3.0, 2.0 / 3.0],
+                [13.0 / 12.0, 1.0 / 3.0, -5.0 / 12.0],
+            ]),
+        );
+    }
+
+    #[test]
+    fn pseudo_inverse_of_rank_deficient_matrix() {
+        let matrix: Matrix<f64, 2, 3> = Matrix::new([[1.0, 2.0, 3.0], [2.0, 4.0, 6.0]]);
+
+        let pseudo_inverse = matrix.pseudo_inverse();
+
+        assert_close(&matrix.mult(&pseudo_inverse).mult(&matrix), &matrix);
+        assert_close(
+            &pseudo_inverse.mult(&matrix).mult(&pseudo_inverse),
+            &pseudo_inverse,
+        );
+    }
+
+    #[test]
+    fn pseudo_inverse_with_tolerance() {
+        let matrix: Matrix<f64, 2, 2> = Matrix::new([[1.0, 0.0], [0.0, 1e-8]]);
+
+        let pseudo_inverse = matrix.pseudo_inverse_with_tolerance(1e-6);
+
+        assert_close(&pseudo_inverse, &Matrix::new([[1.0, 0.0], [0.0, 0.0]]));
+    }
+}
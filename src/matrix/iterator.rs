@@ -1,22 +1,11 @@
-use std::{
-    iter::FromIterator,
-    mem::{swap, MaybeUninit},
-};
+use std::{iter::FromIterator, mem::swap};
 
 use crate::vector::Vector;
 
-use super::Matrix;
+use super::{storage::Storage, Matrix};
 
 impl<'a, T, const M: usize, const N: usize> Matrix<T, M, N> {
-    pub fn into_iter(self) -> IntoIter<T, M, N> {
-        IntoIter {
-            data: self,
-            current: 0,
-            end: M,
-        }
-    }
-
-    pub fn iter(&'a self) -> Iter<T, M, N> {
+    pub fn iter(&'a self) -> Iter<'a, T, M, N> {
         Iter {
             data: &self.inner,
             current: 0,
@@ -24,7 +13,7 @@ impl<'a, T, const M: usize, const N: usize> Matrix<T, M, N> {
         }
     }
 
-    pub fn iter_mut(&'a mut self) -> IterMut<T, M, N> {
+    pub fn iter_mut(&'a mut self) -> IterMut<'a, T, M, N> {
         IterMut {
             data: &mut self.inner,
             current: 0,
@@ -48,19 +37,19 @@ where
     #[inline]
     fn next(&mut self) -> Option<Self::Item> {
         if self.current == self.end {
-            return None;
+            None
         } else {
             let current = self.current;
             self.current += 1;
 
             let mut current_vector: Vector<T, N> = Vector::default();
             swap(&mut current_vector, &mut self.data.inner[current]);
-            return Some(current_vector);
+            Some(current_vector)
         }
     }
 }
 
-impl<'a, T, const M: usize, const N: usize> IntoIterator for Matrix<T, M, N>
+impl<T, const M: usize, const N: usize> IntoIterator for Matrix<T, M, N>
 where
     T: Default + Copy,
 {
@@ -77,7 +66,7 @@ where
 }
 
 pub struct Iter<'a, T, const M: usize, const N: usize> {
-    data: &'a [Vector<T, N>; M],
+    data: &'a [Vector<T, N>],
     current: usize,
     end: usize,
 }
@@ -107,17 +96,17 @@ where
     #[inline]
     fn next(&mut self) -> Option<Self::Item> {
         if self.current == self.end {
-            return None;
+            None
         } else {
             let current = self.current;
             self.current += 1;
-            return Some(&self.data[current]);
+            Some(&self.data[current])
         }
     }
 }
 
 pub struct IterMut<'a, T, const M: usize, const N: usize> {
-    data: &'a mut [Vector<T, N>; M],
+    data: &'a mut [Vector<T, N>],
     current: usize,
     end: usize,
 }
@@ -147,29 +136,20 @@ where
     #[inline]
     fn next(&mut self) -> Option<Self::Item> {
         if self.current == self.end {
-            return None;
+            None
         } else {
             let current = self.current;
             self.current += 1;
             let ptr = self.data.as_mut_ptr();
-            return Some(unsafe { &mut *ptr.add(current) });
+            Some(unsafe { &mut *ptr.add(current) })
         }
     }
 }
 
 impl<T, const M: usize, const N: usize> FromIterator<Vector<T, N>> for Matrix<T, M, N> {
     fn from_iter<I: IntoIterator<Item = Vector<T, N>>>(iter: I) -> Matrix<T, M, N> {
-        let mut uninit_collector: [MaybeUninit<Vector<T, N>>; M] =
-            unsafe { MaybeUninit::uninit().assume_init() };
-
-        let mut idx = 0;
-        for item in iter {
-            *&mut uninit_collector[idx] = MaybeUninit::new(item);
-            idx += 1;
-        }
-
         Matrix {
-            inner: unsafe { uninit_collector.as_ptr().cast::<[Vector<T, N>; M]>().read() },
+            inner: Storage::from_rows(iter.into_iter().collect()),
         }
     }
 }
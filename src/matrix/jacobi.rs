@@ -0,0 +1,238 @@
+use num::Float;
+
+/// Largest number of sweeps over all column pairs before giving up on convergence
+const MAX_SWEEPS: usize = 64;
+
+/// Thin singular value decomposition of a row-major `rows` x `cols` matrix using one-sided
+/// Jacobi rotations.
+///
+/// Returns `(u, singular_values, v)` where `u` is `rows` x `k` and `v` is `cols` x `k`, both
+/// row-major, with `k = min(rows, cols)`. The singular values are sorted in descending order.
+/// Columns of `u` belonging to a zero singular value are left as zero.
+pub(crate) fn svd<T: Float>(data: &[T], rows: usize, cols: usize) -> (Vec<T>, Vec<T>, Vec<T>) {
+    if rows < cols {
+        let mut transposed = Vec::with_capacity(rows * cols);
+        for col in 0..cols {
+            for row in 0..rows {
+                transposed.push(data[row * cols + col]);
+            }
+        }
+
+        let (u, singular_values, v) = svd(&transposed, cols, rows);
+        return (v, singular_values, u);
+    }
+
+    let mut u = data.to_vec();
+    let mut v = vec![T::zero(); cols * cols];
+    for idx in 0..cols {
+        v[idx * cols + idx] = T::one();
+    }
+
+    for _ in 0..MAX_SWEEPS {
+        let mut rotated = false;
+
+        for p in 0..cols {
+            for q in p + 1..cols {
+                let mut alpha = T::zero();
+                let mut beta = T::zero();
+                let mut gamma = T::zero();
+                for row in 0..rows {
+                    let up = u[row * cols + p];
+                    let uq = u[row * cols + q];
+                    alpha = alpha + up * up;
+                    beta = beta + uq * uq;
+                    gamma = gamma + up * uq;
+                }
+
+                if gamma == T::zero() || gamma.abs() <= T::epsilon() * (alpha * beta).sqrt() {
+                    continue;
+                }
+                rotated = true;
+
+                let (cos, sin) = rotation(alpha, beta, gamma);
+                rotate_columns(&mut u, cols, p, q, cos, sin);
+                rotate_columns(&mut v, cols, p, q, cos, sin);
+            }
+        }
+
+        if !rotated {
+            break;
+        }
+    }
+
+    let mut singular_values = vec![T::zero(); cols];
+    for (col, singular_value) in singular_values.iter_mut().enumerate() {
+        let mut acc = T::zero();
+        for row in 0..rows {
+            acc = acc + u[row * cols + col] * u[row * cols + col];
+        }
+        *singular_value = acc.sqrt();
+
+        if *singular_value > T::zero() {
+            for row in 0..rows {
+                u[row * cols + col] = u[row * cols + col] / *singular_value;
+            }
+        }
+    }
+
+    let mut order: Vec<usize> = (0..cols).collect();
+    order.sort_by(|a, b| {
+        singular_values[*b]
+            .partial_cmp(&singular_values[*a])
+            .unwrap_or(std::cmp::Ordering::Equal)
+    });
+
+    (
+        permute_columns(&u, rows, cols, &order),
+        order.iter().map(|idx| singular_values[*idx]).collect(),
+        permute_columns(&v, cols, cols, &order),
+    )
+}
+
+/// Extends the orthonormal columns of a row-major `rows` x `cols` matrix to a square orthonormal
+/// basis. Columns that are exactly zero are replaced in place and the remaining basis vectors are
+/// appended after the existing columns.
+pub(crate) fn complete_basis<T: Float>(data: &[T], rows: usize, cols: usize) -> Vec<T> {
+    let mut columns: Vec<Option<Vec<T>>> = (0..cols)
+        .map(|col| {
+            let column: Vec<T> = (0..rows).map(|row| data[row * cols + col]).collect();
+            if column.iter().all(|num| *num == T::zero()) {
+                None
+            } else {
+                Some(column)
+            }
+        })
+        .collect();
+    columns.resize(rows, None);
+
+    let half = T::one() / (T::one() + T::one());
+    let mut candidate = 0;
+    for idx in 0..rows {
+        if columns[idx].is_some() {
+            continue;
+        }
+
+        while candidate < rows {
+            let mut column = vec![T::zero(); rows];
+            column[candidate] = T::one();
+            candidate += 1;
+
+            // Orthogonalizing twice keeps the basis orthonormal to working precision
+            for _ in 0..2 {
+                for other in columns.iter().flatten() {
+                    let mut projection = T::zero();
+                    for row in 0..rows {
+                        projection = projection + other[row] * column[row];
+                    }
+                    for row in 0..rows {
+                        column[row] = column[row] - projection * other[row];
+                    }
+                }
+            }
+
+            let norm = column
+                .iter()
+                .fold(T::zero(), |acc, num| acc + *num * *num)
+                .sqrt();
+            if norm > half {
+                columns[idx] = Some(column.iter().map(|num| *num / norm).collect());
+                break;
+            }
+        }
+    }
+
+    let mut completed = Vec::with_capacity(rows * rows);
+    for row in 0..rows {
+        for column in columns.iter() {
+            completed.push(column.as_ref().map_or_else(T::zero, |column| column[row]));
+        }
+    }
+
+    completed
+}
+
+/// Cosine and sine of the Jacobi rotation that orthogonalizes two columns with squared norms
+/// `alpha` and `beta` and inner product `gamma`
+pub(crate) fn rotation<T: Float>(alpha: T, beta: T, gamma: T) -> (T, T) {
+    let two = T::one() + T::one();
+    let zeta = (beta - alpha) / (two * gamma);
+    let sign = if zeta < T::zero() {
+        -T::one()
+    } else {
+        T::one()
+    };
+    let tan = sign / (zeta.abs() + (T::one() + zeta * zeta).sqrt());
+    let cos = T::one() / (T::one() + tan * tan).sqrt();
+
+    (cos, cos * tan)
+}
+
+fn rotate_columns<T: Float>(data: &mut [T], cols: usize, p: usize, q: usize, cos: T, sin: T) {
+    for row in data.chunks_mut(cols) {
+        let (xp, xq) = (row[p], row[q]);
+        row[p] = cos * xp - sin * xq;
+        row[q] = sin * xp + cos * xq;
+    }
+}
+
+fn permute_columns<T: Float>(data: &[T], rows: usize, cols: usize, order: &[usize]) -> Vec<T> {
+    let mut permuted = Vec::with_capacity(rows * cols);
+    for row in 0..rows {
+        for col in order {
+            permuted.push(data[row * cols + col]);
+        }
+    }
+
+    permuted
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn svd_singular_values() {
+        let data = [3.0, 2.0, 2.0, 2.0, 3.0, -2.0];
+
+        let (_, singular_values, _) = svd(&data, 2, 3);
+
+        assert!((singular_values[0] - 5.0_f64).abs() < 1e-12);
+        assert!((singular_values[1] - 3.0_f64).abs() < 1e-12);
+    }
+
+    #[test]
+    fn complete_basis_is_orthonormal() {
+        let data = [0.6, 0.0, 0.8, 0.0, 0.0, 0.0];
+
+        let basis = complete_basis(&data, 3, 2);
+
+        for a in 0..3 {
+            for b in 0..3 {
+                let mut acc = 0.0_f64;
+                for row in 0..3 {
+                    acc += basis[row * 3 + a] * basis[row * 3 + b];
+                }
+                let expected = if a == b { 1.0 } else { 0.0 };
+                assert!((acc - expected).abs() < 1e-12);
+            }
+        }
+        assert_eq!(basis[0], 0.6);
+    }
+
+    #[test]
+    fn svd_reconstructs_matrix() {
+        let data = [1.0, 2.0, 3.0, 4.0, 5.0, 6.0];
+
+        let (u, singular_values, v) = svd(&data, 3, 2);
+
+        for row in 0..3 {
+            for col in 0..2 {
+                let mut acc = 0.0_f64;
+                for k in 0..2 {
+                    acc += u[row * 2 + k] * singular_values[k] * v[col * 2 + k];
+                }
+                assert!((acc - data[row * 2 + col]).abs() < 1e-12);
+            }
+        }
+    }
+}
@@ -0,0 +1,299 @@
+use crate::{error::Error, vector::Vector};
+use num::{Float, FromPrimitive, ToPrimitive};
+use std::{
+    fmt::Debug,
+    ops::{Add, AddAssign, Mul, MulAssign, Sub, SubAssign},
+};
+
+use super::Matrix;
+
+/// LU decomposition of a square matrix with partial pivoting.
+///
+/// The factors satisfy `PA = LU` where `P` permutes the rows of `A` such that row `i` of `PA` is
+/// row `permutation[i]` of `A`. `L` is unit lower triangular and `U` is upper triangular.
+///
+/// ```rust
+/// # use sickmath::*;
+/// let matrix: Matrix<f64, 2, 2> = Matrix::new([[1.0, 2.0], [3.0, 4.0]]);
+/// let lu = matrix.lu();
+///
+/// assert_eq!(lu.permutation, [1, 0]);
+/// assert_eq!(lu.determinant(), -2.0);
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct Lu<T, const N: usize> {
+    pub l: Matrix<T, N, N>,
+    pub u: Matrix<T, N, N>,
+    pub permutation: [usize; N],
+    odd_permutation: bool,
+}
+
+impl<T, const N: usize> Matrix<T, N, N>
+where
+    T: Default
+        + Copy
+        + FromPrimitive
+        + ToPrimitive
+        + Mul<Output = T>
+        + MulAssign
+        + Add<Output = T>
+        + AddAssign
+        + Sub<Output = T>
+        + SubAssign
+        + Debug
+        + Float,
+{
+    /// LU decomposition with partial pivoting. A singular matrix will still be decomposed but
+    /// will have a zero on the diagonal of `U`
+    pub fn lu(&self) -> Lu<T, N> {
+        let mut l: Matrix<T, N, N> = Matrix::default();
+        let mut u: Matrix<T, N, N> = self.clone();
+        let mut permutation = [0; N];
+        let mut odd_permutation = false;
+
+        for (idx, row) in permutation.iter_mut().enumerate() {
+            *row = idx;
+        }
+
+        for col in 0..N {
+            let mut pivot_row = col;
+            for row in col + 1..N {
+                if u[row][col].abs() > u[pivot_row][col].abs() {
+                    pivot_row = row;
+                }
+            }
+
+            if pivot_row != col {
+                u.inner.swap(col, pivot_row);
+                l.inner.swap(col, pivot_row);
+                permutation.swap(col, pivot_row);
+                odd_permutation = !odd_permutation;
+            }
+
+            let pivot = u[col][col];
+            if pivot == T::zero() {
+                continue;
+            }
+
+            for row in col + 1..N {
+                let factor = u[row][col] / pivot;
+                l[row][col] = factor;
+                u[row][col] = T::zero();
+
+                for idx in col + 1..N {
+                    let upper = u[col][idx];
+                    u[row][idx] -= factor * upper;
+                }
+            }
+        }
+
+        for idx in 0..N {
+            l[idx][idx] = T::one();
+        }
+
+        Lu {
+            l,
+            u,
+            permutation,
+            odd_permutation,
+        }
+    }
+
+    /// Determinant of the matrix computed from its LU decomposition
+    pub fn determinant(&self) -> T {
+        self.lu().determinant()
+    }
+
+    /// Solves `Ax = b` for `x`. Returns an error if the matrix is singular
+    pub fn solve(&self, b: &Vector<T, N>) -> Result<Vector<T, N>, Error> {
+        self.lu().solve(b)
+    }
+
+    /// Solves `AX = B` for `X`. Returns an error if the matrix is singular
+    pub fn solve_matrix<const P: usize>(
+        &self,
+        b: &Matrix<T, N, P>,
+    ) -> Result<Matrix<T, N, P>, Error> {
+        self.lu().solve_matrix(b)
+    }
+}
+
+impl<T, const N: usize> Lu<T, N>
+where
+    T: Default
+        + Copy
+        + FromPrimitive
+        + ToPrimitive
+        + Mul<Output = T>
+        + MulAssign
+        + Add<Output = T>
+        + AddAssign
+        + Sub<Output = T>
+        + SubAssign
+        + Debug
+        + Float,
+{
+    /// Product of the diagonal of `U` with the sign of the permutation
+    pub fn determinant(&self) -> T {
+        let mut determinant = if self.odd_permutation {
+            -T::one()
+        } else {
+            T::one()
+        };
+
+        for idx in 0..N {
+            determinant *= self.u[idx][idx];
+        }
+
+        determinant
+    }
+
+    /// Whether a pivot of `U` is too small, relative to the largest entry of `U`, to divide by
+    pub fn is_singular(&self) -> bool {
+        let mut largest = T::zero();
+        for row in self.u.iter() {
+            for num in row.iter() {
+                largest = largest.max(num.abs());
+            }
+        }
+
+        let tolerance = largest * T::epsilon() * T::from_usize(N).unwrap_or_else(T::one);
+
+        (0..N).any(|idx| self.u[idx][idx].abs() <= tolerance)
+    }
+
+    /// Solves `Ax = b` using forward and back substitution
+    pub fn solve(&self, b: &Vector<T, N>) -> Result<Vector<T, N>, Error> {
+        if self.is_singular() {
+            return Err(Error::SingularMatrix);
+        }
+
+        let mut x: Vector<T, N> = Vector::default();
+        for idx in 0..N {
+            x[idx] = b[self.permutation[idx]];
+        }
+
+        self.substitute(&mut x);
+
+        Ok(x)
+    }
+
+    /// Solves `AX = B` one column of `B` at a time
+    pub fn solve_matrix<const P: usize>(
+        &self,
+        b: &Matrix<T, N, P>,
+    ) -> Result<Matrix<T, N, P>, Error> {
+        if self.is_singular() {
+            return Err(Error::SingularMatrix);
+        }
+
+        let mut x: Matrix<T, N, P> = Matrix::default();
+        for row in 0..N {
+            x[row] = b[self.permutation[row]].clone();
+        }
+
+        for col in 0..P {
+            let mut column: Vector<T, N> = x.iter().map(|row| row[col]).collect();
+            self.substitute(&mut column);
+
+            for (row, num) in column.iter().enumerate() {
+                x[row][col] = *num;
+            }
+        }
+
+        Ok(x)
+    }
+
+    /// Forward substitution with `L` followed by back substitution with `U`, in place on an
+    /// already permuted right hand side
+    fn substitute(&self, x: &mut Vector<T, N>) {
+        for row in 0..N {
+            let mut acc = x[row];
+            for col in 0..row {
+                acc -= self.l[row][col] * x[col];
+            }
+            x[row] = acc;
+        }
+
+        for row in (0..N).rev() {
+            let mut acc = x[row];
+            for col in row + 1..N {
+                acc -= self.u[row][col] * x[col];
+            }
+            x[row] = acc / self.u[row][row];
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Error, Matrix, Vector};
+
+    fn assert_close(a: f64, b: f64) {
+        assert!((a - b).abs() < 1e-10, "{} is not close to {}", a, b);
+    }
+
+    #[test]
+    fn lu_reconstructs_matrix() {
+        let matrix: Matrix<f64, 3, 3> =
+            Matrix::new([[2.0, 1.0, 1.0], [4.0, -6.0, 0.0], [-2.0, 7.0, 2.0]]);
+
+        let lu = matrix.lu();
+        let product = lu.l.mult(&lu.u);
+
+        for row in 0..3 {
+            for col in 0..3 {
+                assert_close(product[row][col], matrix[lu.permutation[row]][col]);
+            }
+        }
+    }
+
+    #[test]
+    fn lu_determinant() {
+        let matrix: Matrix<f64, 3, 3> =
+            Matrix::new([[2.0, 1.0, 1.0], [4.0, -6.0, 0.0], [-2.0, 7.0, 2.0]]);
+
+        assert_close(matrix.determinant(), -16.0);
+    }
+
+    #[test]
+    fn lu_solve() {
+        let matrix: Matrix<f64, 3, 3> =
+            Matrix::new([[2.0, 1.0, 1.0], [4.0, -6.0, 0.0], [-2.0, 7.0, 2.0]]);
+
+        let b: Vector<f64, 3> = Vector::new([5.0, -2.0, 9.0]);
+
+        let x = matrix.solve(&b).unwrap();
+
+        assert_close(x[0], 1.0);
+        assert_close(x[1], 1.0);
+        assert_close(x[2], 2.0);
+    }
+
+    #[test]
+    fn lu_solve_matrix() {
+        let matrix: Matrix<f64, 2, 2> = Matrix::new([[4.0, 3.0], [6.0, 3.0]]);
+
+        let b: Matrix<f64, 2, 2> = Matrix::new([[1.0, 0.0], [0.0, 1.0]]);
+
+        let x = matrix.solve_matrix(&b).unwrap();
+        let product = matrix.mult(&x);
+
+        for row in 0..2 {
+            for col in 0..2 {
+                assert_close(product[row][col], b[row][col]);
+            }
+        }
+    }
+
+    #[test]
+    fn lu_singular() {
+        let matrix: Matrix<f64, 3, 3> =
+            Matrix::new([[1.0, 2.0, 3.0], [2.0, 4.0, 6.0], [1.0, 0.0, 1.0]]);
+
+        let b: Vector<f64, 3> = Vector::new([1.0, 2.0, 3.0]);
+
+        assert_eq!(matrix.solve(&b), Err(Error::SingularMatrix));
+        assert_close(matrix.determinant(), 0.0);
+    }
+}
@@ -1,12 +1,18 @@
-use crate::math_vector::MathVector;
-use crate::vector::Vector;
+use crate::{
+    math_vector::MathVector,
+    parallel::{for_each_row_block, ParallelElement},
+    vector::Vector,
+};
 use num::{FromPrimitive, ToPrimitive};
 use std::{
     fmt::Debug,
     ops::{Add, AddAssign, Mul, MulAssign, Sub, SubAssign},
 };
 
-use super::Matrix;
+use super::{gemm::gemm, Matrix};
+
+/// Number of multiply-adds below which packing the operands costs more than it saves
+const BLOCKED_MULT_THRESHOLD: usize = 32 * 32 * 32;
 
 impl<T, const M: usize, const N: usize> Matrix<T, M, N>
 where
@@ -20,65 +26,95 @@ where
         + AddAssign
         + Sub<Output = T>
         + SubAssign
-        + Debug,
+        + Debug
+        + ParallelElement,
 {
-    pub fn mult<const P: usize>(&self, matrix2: &Matrix<T, N, P>) -> Matrix<T, M, P>
-    where
-        T: FromPrimitive + ToPrimitive + Debug + Copy + Mul<Output = T> + AddAssign,
-    {
-        let mut multiplied_matrix_data: [Vector<T, P>; M] = unsafe { std::mem::zeroed() };
-
-        if P * M < 300 * 800 {
+    /// Matrix product `AB`
+    ///
+    /// Small products use a plain triple loop, anything larger goes through a cache blocked kernel
+    /// which packs both operands into contiguous panels, so no transpose is needed.
+    pub fn mult<const P: usize>(&self, matrix2: &Matrix<T, N, P>) -> Matrix<T, M, P> {
+        let mut multiplied_matrix: Matrix<T, M, P> = Matrix::default();
+
+        if M * N * P < BLOCKED_MULT_THRESHOLD {
             for row in 0..M {
                 for col in 0..P {
                     let mut acc: T = T::default();
 
-                    for index in 0..P {
+                    for index in 0..N {
                         acc += self[row][index] * matrix2[index][col]
                     }
-                    multiplied_matrix_data[row][col] = acc;
+                    multiplied_matrix[row][col] = acc;
                 }
             }
         } else {
-            for (idx_row, row) in self.into_iter().enumerate() {
-                for (idx_col, col) in matrix2.transpose().into_iter().enumerate() {
-                    multiplied_matrix_data[idx_row][idx_col] =
-                        FromPrimitive::from_isize(row.dot(&col)).expect("Expected valid isize");
-                }
-            }
+            for_each_row_block(
+                &mut multiplied_matrix.inner,
+                M * N * P,
+                |first_row, rows| {
+                    let mut product = vec![T::default(); rows.len() * P];
+                    gemm(
+                        rows.len(),
+                        P,
+                        N,
+                        |row| self[first_row + row].items(),
+                        |row| matrix2[row].items(),
+                        &mut product,
+                    );
+
+                    for (row, chunk) in rows.iter_mut().zip(product.chunks_exact(P)) {
+                        for (num, value) in row.iter_mut().zip(chunk.iter()) {
+                            *num = *value;
+                        }
+                    }
+                },
+            );
         }
 
-        Matrix {
-            inner: multiplied_matrix_data,
+        multiplied_matrix
+    }
+
+    /// Matrix-vector product `Ax`
+    /// ```rust
+    /// # use sickmath::*;
+    /// let matrix: Matrix<i32, 2, 3> = Matrix::new([[1, 2, 3], [4, 5, 6]]);
+    /// let vector: Vector<i32, 3> = Vector::new([1, 0, -1]);
+    ///
+    /// assert_eq!(matrix.mul_vector(&vector), Vector::new([-2, -2]));
+    /// ```
+    pub fn mul_vector(&self, vector: &Vector<T, N>) -> Vector<T, M> {
+        self.iter().map(|row| row.dot(vector)).collect()
+    }
+
+    /// General matrix-vector product `y = αAx + βy` computed in place on `y`
+    pub fn gemv(&self, alpha: T, x: &Vector<T, N>, beta: T, y: &mut Vector<T, M>) {
+        for (row, num) in self.iter().zip(y.iter_mut()) {
+            *num = alpha * row.dot(x) + beta * *num;
         }
     }
 
     pub fn add(&self, matrix2: &Matrix<T, M, N>) -> Matrix<T, M, N> {
-        let mut added_matrix: [Vector<T, N>; M] = unsafe { std::mem::zeroed() };
+        let mut added_matrix: Matrix<T, M, N> = Matrix::default();
 
-        for (idx_row, row) in self.into_iter().enumerate() {
-            for (idx_col, num) in row.into_iter().enumerate() {
-                added_matrix[idx_row][idx_col] = *num + matrix2[idx_row][idx_col];
+        for_each_row_block(&mut added_matrix.inner, M * N, |first_row, rows| {
+            for (idx_row, row) in rows.iter_mut().enumerate() {
+                *row = self[first_row + idx_row].add_vector(&matrix2[first_row + idx_row]);
             }
-        }
+        });
 
-        Matrix {
-            inner: added_matrix,
-        }
+        added_matrix
     }
 
     pub fn subtract(&self, matrix2: &Matrix<T, M, N>) -> Matrix<T, M, N> {
-        let mut subtracted_matrix: [Vector<T, N>; M] = unsafe { std::mem::zeroed() };
+        let mut subtracted_matrix: Matrix<T, M, N> = Matrix::default();
 
-        for (idx_row, row) in self.into_iter().enumerate() {
-            for (idx_col, num) in row.into_iter().enumerate() {
-                subtracted_matrix[idx_row][idx_col] = *num - matrix2[idx_row][idx_col];
+        for_each_row_block(&mut subtracted_matrix.inner, M * N, |first_row, rows| {
+            for (idx_row, row) in rows.iter_mut().enumerate() {
+                *row = self[first_row + idx_row].sub_vector(&matrix2[first_row + idx_row]);
             }
-        }
+        });
 
-        Matrix {
-            inner: subtracted_matrix,
-        }
+        subtracted_matrix
     }
 }
 
@@ -104,6 +140,65 @@ mod tests {
         );
     }
 
+    #[test]
+    fn multiply_float_matrix() {
+        let matrix_1: Matrix<f64, 2, 3> = Matrix::new([[0.5, 1.0, 1.5], [2.0, 2.5, 3.0]]);
+
+        let matrix_2: Matrix<f64, 3, 1> = Matrix::new([[2.0], [0.5], [0.25]]);
+
+        assert_eq!(matrix_1.mult(&matrix_2), Matrix::new([[1.875], [6.0]]));
+    }
+
+    #[test]
+    fn multiply_blocked_matrix() {
+        let mut matrix_1: Matrix<i64, 37, 45> = Matrix::default();
+        let mut matrix_2: Matrix<i64, 45, 29> = Matrix::default();
+
+        for row in 0..37 {
+            for col in 0..45 {
+                matrix_1[row][col] = ((row * 3 + col) % 11) as i64 - 5;
+            }
+        }
+        for row in 0..45 {
+            for col in 0..29 {
+                matrix_2[row][col] = ((row + col * 7) % 13) as i64 - 6;
+            }
+        }
+
+        let multiplied_matrix = matrix_1.mult(&matrix_2);
+
+        for row in 0..37 {
+            for col in 0..29 {
+                let expected: i64 = (0..45)
+                    .map(|idx| matrix_1[row][idx] * matrix_2[idx][col])
+                    .sum();
+                assert_eq!(multiplied_matrix[row][col], expected);
+            }
+        }
+    }
+
+    #[test]
+    fn multiply_large_vector() {
+        let matrix: Matrix<i32, 2, 3> =
+            Matrix::new([Vector::new_large([1, 2, 3]), Vector::new([4, 5, 6])]);
+
+        let vector: Vector<i32, 3> = Vector::new_large([2, 1, 0]);
+
+        assert_eq!(matrix.mul_vector(&vector), Vector::new([4, 13]));
+    }
+
+    #[test]
+    fn gemv_matrix() {
+        let matrix: Matrix<f64, 2, 2> = Matrix::new([[1.0, 2.0], [3.0, 4.0]]);
+
+        let x: Vector<f64, 2> = Vector::new([1.0, 1.0]);
+        let mut y: Vector<f64, 2> = Vector::new([1.0, -1.0]);
+
+        matrix.gemv(2.0, &x, 0.5, &mut y);
+
+        assert_eq!(y, Vector::new([6.5, 13.5]));
+    }
+
     #[test]
     fn add_matrix() {
         let matrix_array: Matrix<u8, 3, 2> = Matrix::new([[1, 2], [3, 4], [5, 6]]);
@@ -112,8 +207,6 @@ mod tests {
             Matrix::new([Vec::from([3, 1]), Vec::from([9, 6]), Vec::from([2, 3])]);
 
         let added_matrix = matrix_array.add(&matrix_vec);
-        println!("{:?}", matrix_vec);
-        println!("{:?}", added_matrix);
 
         assert_eq!(
             added_matrix,
@@ -0,0 +1,696 @@
+use crate::{math_vector::MathVector, parallel::ParallelElement, vector::Vector};
+use num::{FromPrimitive, ToPrimitive};
+use std::{
+    fmt::Debug,
+    ops::{Add, AddAssign, Mul, MulAssign, Neg, Sub, SubAssign},
+};
+
+use super::Matrix;
+
+impl<T, const M: usize, const N: usize> Add for Matrix<T, M, N>
+where
+    T: Default
+        + Copy
+        + FromPrimitive
+        + ToPrimitive
+        + Mul<Output = T>
+        + MulAssign
+        + Add<Output = T>
+        + AddAssign
+        + Sub<Output = T>
+        + SubAssign
+        + Debug
+        + ParallelElement,
+{
+    type Output = Self;
+
+    fn add(mut self, rhs: Self) -> Self {
+        self += &rhs;
+        self
+    }
+}
+
+impl<'a, T, const M: usize, const N: usize> Add<&'a Matrix<T, M, N>> for &'a Matrix<T, M, N>
+where
+    T: Default
+        + Copy
+        + FromPrimitive
+        + ToPrimitive
+        + Mul<Output = T>
+        + MulAssign
+        + Add<Output = T>
+        + AddAssign
+        + Sub<Output = T>
+        + SubAssign
+        + Debug
+        + ParallelElement,
+{
+    type Output = Matrix<T, M, N>;
+
+    fn add(self, rhs: Self) -> Matrix<T, M, N> {
+        Matrix::add(self, rhs)
+    }
+}
+
+impl<'a, T, const M: usize, const N: usize> Add<&'a Matrix<T, M, N>> for Matrix<T, M, N>
+where
+    T: Default
+        + Copy
+        + FromPrimitive
+        + ToPrimitive
+        + Mul<Output = T>
+        + MulAssign
+        + Add<Output = T>
+        + AddAssign
+        + Sub<Output = T>
+        + SubAssign
+        + Debug
+        + ParallelElement,
+{
+    type Output = Self;
+
+    fn add(mut self, rhs: &'a Matrix<T, M, N>) -> Self {
+        self += rhs;
+        self
+    }
+}
+
+impl<T, const M: usize, const N: usize> Add<Matrix<T, M, N>> for &Matrix<T, M, N>
+where
+    T: Default
+        + Copy
+        + FromPrimitive
+        + ToPrimitive
+        + Mul<Output = T>
+        + MulAssign
+        + Add<Output = T>
+        + AddAssign
+        + Sub<Output = T>
+        + SubAssign
+        + Debug
+        + ParallelElement,
+{
+    type Output = Matrix<T, M, N>;
+
+    fn add(self, rhs: Matrix<T, M, N>) -> Matrix<T, M, N> {
+        Matrix::add(self, &rhs)
+    }
+}
+
+impl<T, const M: usize, const N: usize> AddAssign for Matrix<T, M, N>
+where
+    T: Default
+        + Copy
+        + FromPrimitive
+        + ToPrimitive
+        + Mul<Output = T>
+        + MulAssign
+        + Add<Output = T>
+        + AddAssign
+        + Sub<Output = T>
+        + SubAssign
+        + Debug,
+{
+    fn add_assign(&mut self, rhs: Self) {
+        *self += &rhs;
+    }
+}
+
+impl<'a, T, const M: usize, const N: usize> AddAssign<&'a Matrix<T, M, N>> for Matrix<T, M, N>
+where
+    T: Default
+        + Copy
+        + FromPrimitive
+        + ToPrimitive
+        + Mul<Output = T>
+        + MulAssign
+        + Add<Output = T>
+        + AddAssign
+        + Sub<Output = T>
+        + SubAssign
+        + Debug,
+{
+    fn add_assign(&mut self, rhs: &'a Matrix<T, M, N>) {
+        for (row, rhs_row) in self.inner.iter_mut().zip(rhs.iter()) {
+            row.add_vector_mut(rhs_row);
+        }
+    }
+}
+
+impl<T, const M: usize, const N: usize> Sub for Matrix<T, M, N>
+where
+    T: Default
+        + Copy
+        + FromPrimitive
+        + ToPrimitive
+        + Mul<Output = T>
+        + MulAssign
+        + Add<Output = T>
+        + AddAssign
+        + Sub<Output = T>
+        + SubAssign
+        + Debug
+        + ParallelElement,
+{
+    type Output = Self;
+
+    fn sub(mut self, rhs: Self) -> Self {
+        self -= &rhs;
+        self
+    }
+}
+
+impl<'a, T, const M: usize, const N: usize> Sub<&'a Matrix<T, M, N>> for &'a Matrix<T, M, N>
+where
+    T: Default
+        + Copy
+        + FromPrimitive
+        + ToPrimitive
+        + Mul<Output = T>
+        + MulAssign
+        + Add<Output = T>
+        + AddAssign
+        + Sub<Output = T>
+        + SubAssign
+        + Debug
+        + ParallelElement,
+{
+    type Output = Matrix<T, M, N>;
+
+    fn sub(self, rhs: Self) -> Matrix<T, M, N> {
+        self.subtract(rhs)
+    }
+}
+
+impl<'a, T, const M: usize, const N: usize> Sub<&'a Matrix<T, M, N>> for Matrix<T, M, N>
+where
+    T: Default
+        + Copy
+        + FromPrimitive
+        + ToPrimitive
+        + Mul<Output = T>
+        + MulAssign
+        + Add<Output = T>
+        + AddAssign
+        + Sub<Output = T>
+        + SubAssign
+        + Debug
+        + ParallelElement,
+{
+    type Output = Self;
+
+    fn sub(mut self, rhs: &'a Matrix<T, M, N>) -> Self {
+        self -= rhs;
+        self
+    }
+}
+
+impl<T, const M: usize, const N: usize> Sub<Matrix<T, M, N>> for &Matrix<T, M, N>
+where
+    T: Default
+        + Copy
+        + FromPrimitive
+        + ToPrimitive
+        + Mul<Output = T>
+        + MulAssign
+        + Add<Output = T>
+        + AddAssign
+        + Sub<Output = T>
+        + SubAssign
+        + Debug
+        + ParallelElement,
+{
+    type Output = Matrix<T, M, N>;
+
+    fn sub(self, rhs: Matrix<T, M, N>) -> Matrix<T, M, N> {
+        self.subtract(&rhs)
+    }
+}
+
+impl<T, const M: usize, const N: usize> SubAssign for Matrix<T, M, N>
+where
+    T: Default
+        + Copy
+        + FromPrimitive
+        + ToPrimitive
+        + Mul<Output = T>
+        + MulAssign
+        + Add<Output = T>
+        + AddAssign
+        + Sub<Output = T>
+        + SubAssign
+        + Debug,
+{
+    fn sub_assign(&mut self, rhs: Self) {
+        *self -= &rhs;
+    }
+}
+
+impl<'a, T, const M: usize, const N: usize> SubAssign<&'a Matrix<T, M, N>> for Matrix<T, M, N>
+where
+    T: Default
+        + Copy
+        + FromPrimitive
+        + ToPrimitive
+        + Mul<Output = T>
+        + MulAssign
+        + Add<Output = T>
+        + AddAssign
+        + Sub<Output = T>
+        + SubAssign
+        + Debug,
+{
+    fn sub_assign(&mut self, rhs: &'a Matrix<T, M, N>) {
+        for (row, rhs_row) in self.inner.iter_mut().zip(rhs.iter()) {
+            row.sub_vector_mut(rhs_row);
+        }
+    }
+}
+
+/// Matrix product. The inner dimensions are checked at compile time
+impl<T, const M: usize, const N: usize, const P: usize> Mul<Matrix<T, N, P>> for Matrix<T, M, N>
+where
+    T: Default
+        + Copy
+        + FromPrimitive
+        + ToPrimitive
+        + Mul<Output = T>
+        + MulAssign
+        + Add<Output = T>
+        + AddAssign
+        + Sub<Output = T>
+        + SubAssign
+        + Debug
+        + ParallelElement,
+{
+    type Output = Matrix<T, M, P>;
+
+    fn mul(self, rhs: Matrix<T, N, P>) -> Matrix<T, M, P> {
+        self.mult(&rhs)
+    }
+}
+
+/// Matrix product. The inner dimensions are checked at compile time
+impl<'a, T, const M: usize, const N: usize, const P: usize> Mul<&'a Matrix<T, N, P>>
+    for &'a Matrix<T, M, N>
+where
+    T: Default
+        + Copy
+        + FromPrimitive
+        + ToPrimitive
+        + Mul<Output = T>
+        + MulAssign
+        + Add<Output = T>
+        + AddAssign
+        + Sub<Output = T>
+        + SubAssign
+        + Debug
+        + ParallelElement,
+{
+    type Output = Matrix<T, M, P>;
+
+    fn mul(self, rhs: &'a Matrix<T, N, P>) -> Matrix<T, M, P> {
+        self.mult(rhs)
+    }
+}
+
+/// Matrix product. The inner dimensions are checked at compile time
+impl<'a, T, const M: usize, const N: usize, const P: usize> Mul<&'a Matrix<T, N, P>>
+    for Matrix<T, M, N>
+where
+    T: Default
+        + Copy
+        + FromPrimitive
+        + ToPrimitive
+        + Mul<Output = T>
+        + MulAssign
+        + Add<Output = T>
+        + AddAssign
+        + Sub<Output = T>
+        + SubAssign
+        + Debug
+        + ParallelElement,
+{
+    type Output = Matrix<T, M, P>;
+
+    fn mul(self, rhs: &'a Matrix<T, N, P>) -> Matrix<T, M, P> {
+        self.mult(rhs)
+    }
+}
+
+/// Matrix product. The inner dimensions are checked at compile time
+impl<T, const M: usize, const N: usize, const P: usize> Mul<Matrix<T, N, P>> for &Matrix<T, M, N>
+where
+    T: Default
+        + Copy
+        + FromPrimitive
+        + ToPrimitive
+        + Mul<Output = T>
+        + MulAssign
+        + Add<Output = T>
+        + AddAssign
+        + Sub<Output = T>
+        + SubAssign
+        + Debug
+        + ParallelElement,
+{
+    type Output = Matrix<T, M, P>;
+
+    fn mul(self, rhs: Matrix<T, N, P>) -> Matrix<T, M, P> {
+        self.mult(&rhs)
+    }
+}
+
+/// Matrix product with a square right hand side which keeps the dimensions of the matrix
+impl<T, const M: usize, const N: usize> MulAssign<Matrix<T, N, N>> for Matrix<T, M, N>
+where
+    T: Default
+        + Copy
+        + FromPrimitive
+        + ToPrimitive
+        + Mul<Output = T>
+        + MulAssign
+        + Add<Output = T>
+        + AddAssign
+        + Sub<Output = T>
+        + SubAssign
+        + Debug
+        + ParallelElement,
+{
+    fn mul_assign(&mut self, rhs: Matrix<T, N, N>) {
+        *self *= &rhs;
+    }
+}
+
+/// Matrix product with a square right hand side which keeps the dimensions of the matrix
+impl<'a, T, const M: usize, const N: usize> MulAssign<&'a Matrix<T, N, N>> for Matrix<T, M, N>
+where
+    T: Default
+        + Copy
+        + FromPrimitive
+        + ToPrimitive
+        + Mul<Output = T>
+        + MulAssign
+        + Add<Output = T>
+        + AddAssign
+        + Sub<Output = T>
+        + SubAssign
+        + Debug
+        + ParallelElement,
+{
+    fn mul_assign(&mut self, rhs: &'a Matrix<T, N, N>) {
+        *self = self.mult(rhs);
+    }
+}
+
+/// Matrix-vector product
+impl<T, const M: usize, const N: usize> Mul<Vector<T, N>> for Matrix<T, M, N>
+where
+    T: Default
+        + Copy
+        + FromPrimitive
+        + ToPrimitive
+        + Mul<Output = T>
+        + MulAssign
+        + Add<Output = T>
+        + AddAssign
+        + Sub<Output = T>
+        + SubAssign
+        + Debug
+        + ParallelElement,
+{
+    type Output = Vector<T, M>;
+
+    fn mul(self, rhs: Vector<T, N>) -> Vector<T, M> {
+        &self * &rhs
+    }
+}
+
+/// Matrix-vector product
+impl<'a, T, const M: usize, const N: usize> Mul<&'a Vector<T, N>> for &'a Matrix<T, M, N>
+where
+    T: Default
+        + Copy
+        + FromPrimitive
+        + ToPrimitive
+        + Mul<Output = T>
+        + MulAssign
+        + Add<Output = T>
+        + AddAssign
+        + Sub<Output = T>
+        + SubAssign
+        + Debug
+        + ParallelElement,
+{
+    type Output = Vector<T, M>;
+
+    fn mul(self, rhs: &'a Vector<T, N>) -> Vector<T, M> {
+        self.mul_vector(rhs)
+    }
+}
+
+/// Matrix-vector product
+impl<'a, T, const M: usize, const N: usize> Mul<&'a Vector<T, N>> for Matrix<T, M, N>
+where
+    T: Default
+        + Copy
+        + FromPrimitive
+        + ToPrimitive
+        + Mul<Output = T>
+        + MulAssign
+        + Add<Output = T>
+        + AddAssign
+        + Sub<Output = T>
+        + SubAssign
+        + Debug
+        + ParallelElement,
+{
+    type Output = Vector<T, M>;
+
+    fn mul(self, rhs: &'a Vector<T, N>) -> Vector<T, M> {
+        self.mul_vector(rhs)
+    }
+}
+
+/// Matrix-vector product
+impl<T, const M: usize, const N: usize> Mul<Vector<T, N>> for &Matrix<T, M, N>
+where
+    T: Default
+        + Copy
+        + FromPrimitive
+        + ToPrimitive
+        + Mul<Output = T>
+        + MulAssign
+        + Add<Output = T>
+        + AddAssign
+        + Sub<Output = T>
+        + SubAssign
+        + Debug
+        + ParallelElement,
+{
+    type Output = Vector<T, M>;
+
+    fn mul(self, rhs: Vector<T, N>) -> Vector<T, M> {
+        self.mul_vector(&rhs)
+    }
+}
+
+/// Scalar multiplication
+impl<T, const M: usize, const N: usize> Mul<T> for Matrix<T, M, N>
+where
+    T: Default
+        + Copy
+        + FromPrimitive
+        + ToPrimitive
+        + Mul<Output = T>
+        + MulAssign
+        + Add<Output = T>
+        + AddAssign
+        + Sub<Output = T>
+        + SubAssign
+        + Debug,
+{
+    type Output = Self;
+
+    fn mul(mut self, rhs: T) -> Self {
+        self *= rhs;
+        self
+    }
+}
+
+/// Scalar multiplication
+impl<T, const M: usize, const N: usize> Mul<T> for &Matrix<T, M, N>
+where
+    T: Default
+        + Copy
+        + FromPrimitive
+        + ToPrimitive
+        + Mul<Output = T>
+        + MulAssign
+        + Add<Output = T>
+        + AddAssign
+        + Sub<Output = T>
+        + SubAssign
+        + Debug,
+{
+    type Output = Matrix<T, M, N>;
+
+    fn mul(self, rhs: T) -> Matrix<T, M, N> {
+        self.clone() * rhs
+    }
+}
+
+/// Scalar multiplication
+impl<T, const M: usize, const N: usize> MulAssign<T> for Matrix<T, M, N>
+where
+    T: Default
+        + Copy
+        + FromPrimitive
+        + ToPrimitive
+        + Mul<Output = T>
+        + MulAssign
+        + Add<Output = T>
+        + AddAssign
+        + Sub<Output = T>
+        + SubAssign
+        + Debug,
+{
+    fn mul_assign(&mut self, rhs: T) {
+        for row in self.inner.iter_mut() {
+            row.scalar_mut(rhs);
+        }
+    }
+}
+
+impl<T, const M: usize, const N: usize> Neg for Matrix<T, M, N>
+where
+    T: Copy + Neg<Output = T>,
+{
+    type Output = Self;
+
+    fn neg(mut self) -> Self {
+        for row in self.inner.iter_mut() {
+            for num in row.iter_mut() {
+                *num = -*num;
+            }
+        }
+
+        self
+    }
+}
+
+impl<T, const M: usize, const N: usize> Neg for &Matrix<T, M, N>
+where
+    T: Copy + Neg<Output = T>,
+{
+    type Output = Matrix<T, M, N>;
+
+    fn neg(self) -> Matrix<T, M, N> {
+        -self.clone()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Matrix, Vector};
+
+    #[test]
+    fn add_matrix() {
+        let matrix_1: Matrix<u8, 2, 2> = Matrix::new([[1, 2], [3, 4]]);
+
+        let matrix_2: Matrix<u8, 2, 2> = Matrix::new([[5, 6], [7, 8]]);
+
+        let added_matrix = Matrix::new([[6, 8], [10, 12]]);
+
+        assert_eq!(&matrix_1 + &matrix_2, added_matrix);
+        assert_eq!(matrix_1.clone() + &matrix_2, added_matrix);
+        assert_eq!(&matrix_1 + matrix_2.clone(), added_matrix);
+        assert_eq!(matrix_1 + matrix_2, added_matrix);
+    }
+
+    #[test]
+    fn add_assign_matrix() {
+        let mut matrix: Matrix<u8, 2, 2> = Matrix::new([[1, 2], [3, 4]]);
+
+        matrix += Matrix::new([[5, 6], [7, 8]]);
+
+        assert_eq!(matrix, Matrix::new([[6, 8], [10, 12]]));
+    }
+
+    #[test]
+    fn sub_matrix() {
+        let matrix_1: Matrix<i8, 2, 2> = Matrix::new([[5, 6], [7, 8]]);
+
+        let matrix_2: Matrix<i8, 2, 2> = Matrix::new([[1, 2], [3, 9]]);
+
+        let subtracted_matrix = Matrix::new([[4, 4], [4, -1]]);
+
+        assert_eq!(&matrix_1 - &matrix_2, subtracted_matrix);
+        assert_eq!(matrix_1.clone() - &matrix_2, subtracted_matrix);
+        assert_eq!(&matrix_1 - matrix_2.clone(), subtracted_matrix);
+        assert_eq!(matrix_1 - matrix_2, subtracted_matrix);
+    }
+
+    #[test]
+    fn sub_assign_matrix() {
+        let mut matrix: Matrix<u8, 2, 2> = Matrix::new([[5, 6], [7, 8]]);
+
+        matrix -= &Matrix::new([[1, 2], [3, 4]]);
+
+        assert_eq!(matrix, Matrix::new([[4, 4], [4, 4]]));
+    }
+
+    #[test]
+    fn mul_matrix() {
+        let matrix_1: Matrix<u8, 3, 2> = Matrix::new([[1, 2], [3, 4], [5, 6]]);
+
+        let matrix_2: Matrix<u8, 2, 2> = Matrix::new([[3, 1], [9, 6]]);
+
+        let multiplied_matrix = Matrix::new([[21, 13], [45, 27], [69, 41]]);
+
+        assert_eq!(&matrix_1 * &matrix_2, multiplied_matrix);
+        assert_eq!(matrix_1.clone() * &matrix_2, multiplied_matrix);
+        assert_eq!(&matrix_1 * matrix_2.clone(), multiplied_matrix);
+        assert_eq!(matrix_1 * matrix_2, multiplied_matrix);
+    }
+
+    #[test]
+    fn mul_assign_matrix() {
+        let mut matrix: Matrix<u8, 3, 2> = Matrix::new([[1, 2], [3, 4], [5, 6]]);
+
+        matrix *= Matrix::new([[3, 1], [9, 6]]);
+
+        assert_eq!(matrix, Matrix::new([[21, 13], [45, 27], [69, 41]]));
+    }
+
+    #[test]
+    fn mul_vector() {
+        let matrix: Matrix<i32, 2, 3> = Matrix::new([[1, 2, 3], [4, 5, 6]]);
+
+        let vector: Vector<i32, 3> = Vector::new([1, 0, -1]);
+
+        assert_eq!(&matrix * &vector, Vector::new([-2, -2]));
+        assert_eq!(matrix.clone() * &vector, Vector::new([-2, -2]));
+        assert_eq!(&matrix * vector.clone(), Vector::new([-2, -2]));
+        assert_eq!(matrix * vector, Vector::new([-2, -2]));
+    }
+
+    #[test]
+    fn mul_scalar() {
+        let mut matrix: Matrix<f64, 2, 2> = Matrix::new([[1.0, 2.0], [3.0, 4.0]]);
+
+        assert_eq!(&matrix * 0.5, Matrix::new([[0.5, 1.0], [1.5, 2.0]]));
+
+        matrix *= 2.0;
+
+        assert_eq!(matrix, Matrix::new([[2.0, 4.0], [6.0, 8.0]]));
+    }
+
+    #[test]
+    fn neg_matrix() {
+        let matrix: Matrix<i8, 2, 2> = Matrix::new([[1, -2], [3, 0]]);
+
+        assert_eq!(-&matrix, Matrix::new([[-1, 2], [-3, 0]]));
+        assert_eq!(-matrix, Matrix::new([[-1, 2], [-3, 0]]));
+    }
+}
@@ -0,0 +1,261 @@
+use crate::{error::Error, vector::Vector};
+use num::{Float, FromPrimitive, ToPrimitive};
+use std::{
+    fmt::Debug,
+    ops::{Add, AddAssign, Mul, MulAssign, Sub, SubAssign},
+};
+
+use super::Matrix;
+
+/// A Householder reflection `I - beta * v * vᵀ` acting on the rows starting at `offset`
+struct Reflector<T> {
+    offset: usize,
+    v: Vec<T>,
+    beta: T,
+}
+
+impl<T> Reflector<T>
+where
+    T: Float + SubAssign,
+{
+    /// Applies the reflection from the left to every column of `matrix`
+    fn apply<const M: usize, const C: usize>(&self, matrix: &mut Matrix<T, M, C>)
+    where
+        T: Default + FromPrimitive + Debug,
+    {
+        for col in 0..C {
+            let mut acc = T::zero();
+            for (idx, num) in self.v.iter().enumerate() {
+                acc = acc + *num * matrix[self.offset + idx][col];
+            }
+
+            let scaled = acc * self.beta;
+            for (idx, num) in self.v.iter().enumerate() {
+                matrix[self.offset + idx][col] -= scaled * *num;
+            }
+        }
+    }
+}
+
+impl<T, const M: usize, const N: usize> Matrix<T, M, N>
+where
+    T: Default
+        + Copy
+        + FromPrimitive
+        + ToPrimitive
+        + Mul<Output = T>
+        + MulAssign
+        + Add<Output = T>
+        + AddAssign
+        + Sub<Output = T>
+        + SubAssign
+        + Debug
+        + Float,
+{
+    /// QR decomposition using Householder reflections.
+    ///
+    /// Returns an orthogonal `Q` and an upper triangular `R` such that `A = QR`.
+    /// ```rust
+    /// # use sickmath::*;
+    /// let matrix: Matrix<f64, 3, 2> = Matrix::new([[3.0, 1.0], [4.0, 2.0], [0.0, 2.0]]);
+    /// let (q, r) = matrix.qr();
+    ///
+    /// assert_eq!(r[1][0], 0.0);
+    /// assert_eq!(r[2][1], 0.0);
+    /// ```
+    pub fn qr(&self) -> (Matrix<T, M, M>, Matrix<T, M, N>) {
+        let (r, reflectors) = self.householder();
+
+        let mut q: Matrix<T, M, M> = Matrix::identity();
+        for reflector in reflectors.iter().rev() {
+            reflector.apply(&mut q);
+        }
+
+        (q, r)
+    }
+
+    /// Thin (economy) QR decomposition where `Q` only keeps the first `N` columns and `R` the
+    /// first `N` rows. Returns an error if the matrix has fewer rows than columns
+    pub fn thin_qr(&self) -> Result<(Matrix<T, M, N>, Matrix<T, N, N>), Error> {
+        if M < N {
+            return Err(Error::DimensionMismatch {
+                expected: N,
+                found: M,
+            });
+        }
+
+        let (r, reflectors) = self.householder();
+
+        let mut q: Matrix<T, M, N> = Matrix::default();
+        for idx in 0..N {
+            q[idx][idx] = T::one();
+        }
+        for reflector in reflectors.iter().rev() {
+            reflector.apply(&mut q);
+        }
+
+        let mut thin_r: Matrix<T, N, N> = Matrix::default();
+        for row in 0..N {
+            thin_r[row] = r[row].clone();
+        }
+
+        Ok((q, thin_r))
+    }
+
+    /// Least squares solution minimizing `‖Ax - b‖` using the thin QR decomposition. Returns an
+    /// error if the matrix has fewer rows than columns or doesn't have full column rank
+    pub fn least_squares(&self, b: &Vector<T, M>) -> Result<Vector<T, N>, Error> {
+        let (q, r) = self.thin_qr()?;
+
+        let mut largest = T::zero();
+        for idx in 0..N {
+            largest = largest.max(r[idx][idx].abs());
+        }
+        let tolerance = largest * T::epsilon() * T::from_usize(M).unwrap_or_else(T::one);
+
+        let mut x: Vector<T, N> = Vector::default();
+        for row in (0..N).rev() {
+            if r[row][row].abs() <= tolerance {
+                return Err(Error::SingularMatrix);
+            }
+
+            let mut acc = T::zero();
+            for idx in 0..M {
+                acc += q[idx][row] * b[idx];
+            }
+            for col in row + 1..N {
+                acc -= r[row][col] * x[col];
+            }
+
+            x[row] = acc / r[row][row];
+        }
+
+        Ok(x)
+    }
+
+    /// Reduces the matrix to upper triangular form, returning it along with the reflections used
+    fn householder(&self) -> (Matrix<T, M, N>, Vec<Reflector<T>>) {
+        let mut r = self.clone();
+        let mut reflectors = Vec::with_capacity(N);
+
+        for col in 0..N.min(M.saturating_sub(1)) {
+            let mut norm = T::zero();
+            for row in col..M {
+                norm += r[row][col] * r[row][col];
+            }
+            norm = norm.sqrt();
+
+            if norm == T::zero() {
+                continue;
+            }
+
+            let alpha = if r[col][col] > T::zero() { -norm } else { norm };
+
+            let mut v: Vec<T> = (col..M).map(|row| r[row][col]).collect();
+            v[0] -= alpha;
+
+            let mut squared_norm = T::zero();
+            for num in v.iter() {
+                squared_norm += *num * *num;
+            }
+
+            let reflector = Reflector {
+                offset: col,
+                v,
+                beta: (T::one() + T::one()) / squared_norm,
+            };
+            reflector.apply(&mut r);
+
+            r[col][col] = alpha;
+            for row in col + 1..M {
+                r[row][col] = T::zero();
+            }
+
+            reflectors.push(reflector);
+        }
+
+        (r, reflectors)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Error, Matrix, Vector};
+
+    fn assert_close<const M: usize, const N: usize>(a: &Matrix<f64, M, N>, b: &Matrix<f64, M, N>) {
+        for row in 0..M {
+            for col in 0..N {
+                assert!(
+                    (a[row][col] - b[row][col]).abs() < 1e-10,
+                    "{:?} is not close to {:?}",
+                    a,
+                    b
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn qr_reconstructs_matrix() {
+        let matrix: Matrix<f64, 3, 3> =
+            Matrix::new([[12.0, -51.0, 4.0], [6.0, 167.0, -68.0], [-4.0, 24.0, -41.0]]);
+
+        let (q, r) = matrix.qr();
+
+        assert_close(&q.mult(&r), &matrix);
+        assert_close(&q.transpose().mult(&q), &Matrix::identity());
+        assert_close(
+            &r,
+            &Matrix::new([[-14.0, -21.0, 14.0], [0.0, -175.0, 70.0], [0.0, 0.0, -35.0]]),
+        );
+    }
+
+    #[test]
+    fn qr_wide_matrix() {
+        let matrix: Matrix<f64, 2, 3> = Matrix::new([[1.0, 2.0, 3.0], [4.0, 5.0, 6.0]]);
+
+        let (q, r) = matrix.qr();
+
+        assert_close(&q.mult(&r), &matrix);
+        assert_close(&q.transpose().mult(&q), &Matrix::identity());
+        assert_eq!(r[1][0], 0.0);
+    }
+
+    #[test]
+    fn thin_qr_reconstructs_matrix() {
+        let matrix: Matrix<f64, 4, 2> =
+            Matrix::new([[1.0, 2.0], [3.0, 4.0], [5.0, 6.0], [7.0, 8.0]]);
+
+        let (q, r) = matrix.thin_qr().unwrap();
+
+        assert_close(&q.mult(&r), &matrix);
+        assert_close(&q.transpose().mult(&q), &Matrix::identity());
+        assert_eq!(r[1][0], 0.0);
+    }
+
+    #[test]
+    fn thin_qr_wide_matrix() {
+        let matrix: Matrix<f64, 2, 3> = Matrix::new([[1.0, 2.0, 3.0], [4.0, 5.0, 6.0]]);
+
+        assert_eq!(
+            matrix.thin_qr().err(),
+            Some(Error::DimensionMismatch {
+                expected: 3,
+                found: 2
+            })
+        );
+    }
+
+    #[test]
+    fn least_squares_line_fit() {
+        let matrix: Matrix<f64, 4, 2> =
+            Matrix::new([[1.0, 0.0], [1.0, 1.0], [1.0, 2.0], [1.0, 3.0]]);
+
+        let b: Vector<f64, 4> = Vector::new([1.0, 3.0, 5.0, 7.0]);
+
+        let x = matrix.least_squares(&b).unwrap();
+
+        assert!((x[0] - 1.0).abs() < 1e-10);
+        assert!((x[1] - 2.0).abs() < 1e-10);
+    }
+}
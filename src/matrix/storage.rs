@@ -0,0 +1,128 @@
+use std::{
+    fmt,
+    ops::{Deref, DerefMut},
+};
+
+use crate::{error::Error, vector::Vector};
+
+/// The `M` rows of a `Matrix`, kept in a single heap allocation.
+///
+/// Like any `Vector`, a row is a `SmallVector` holding its items inline when `N` is small and a
+/// `LargeVector` with its own buffer otherwise, so small rows end up inside this allocation and
+/// large rows are only pointed to from it. There is no inline variant for small matrices: an enum
+/// is as large as its largest variant, so `Matrix<f64, 1000, 1000>` would take 8 MB of stack even
+/// while holding the heap variant.
+#[derive(Clone, PartialEq)]
+pub(crate) struct Storage<T, const M: usize, const N: usize>(Box<[Vector<T, N>]>);
+
+impl<T, const M: usize, const N: usize> Storage<T, M, N> {
+    /// Moves the rows into the buffer. Returns an error if there aren't exactly `M` rows
+    pub(crate) fn try_from_rows(rows: Vec<Vector<T, N>>) -> Result<Self, Error> {
+        if rows.len() != M {
+            return Err(Error::LengthMismatch {
+                expected: M,
+                found: rows.len(),
+            });
+        }
+
+        Ok(Storage(rows.into_boxed_slice()))
+    }
+
+    /// Same as `try_from_rows` but panics if there aren't exactly `M` rows
+    pub(crate) fn from_rows(rows: Vec<Vector<T, N>>) -> Self {
+        Self::try_from_rows(rows).unwrap_or_else(|err: Error| panic!("{}", err))
+    }
+
+    /// Splits row-major items into rows, which are small or large vectors depending on `N`.
+    /// Panics if there aren't exactly `M * N` items
+    pub(crate) fn from_row_major(items: &[T]) -> Self
+    where
+        T: Default + Copy,
+    {
+        assert_eq!(
+            items.len(),
+            M * N,
+            "expected M * N = {} row-major items, found {}",
+            M * N,
+            items.len()
+        );
+
+        Storage(
+            (0..M)
+                .map(|row| items[row * N..(row + 1) * N].iter().copied().collect())
+                .collect(),
+        )
+    }
+}
+
+impl<T, const M: usize, const N: usize> Deref for Storage<T, M, N> {
+    type Target = [Vector<T, N>];
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl<T, const M: usize, const N: usize> DerefMut for Storage<T, M, N> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.0
+    }
+}
+
+impl<T, const M: usize, const N: usize> fmt::Debug for Storage<T, M, N>
+where
+    T: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.0.fmt(f)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Storage;
+    use crate::{Error, Matrix, Vector};
+
+    #[test]
+    fn large_matrix_fits_on_stack() {
+        let mut matrix: Matrix<f64, 1000, 1000> = Matrix::default();
+        matrix[999][3] = 2.0;
+
+        let transposed = matrix.transpose();
+
+        assert_eq!(transposed[3][999], 2.0);
+        assert_eq!(
+            std::mem::size_of::<Matrix<f64, 1000, 1000>>(),
+            2 * std::mem::size_of::<usize>()
+        );
+    }
+
+    #[test]
+    fn rows_are_picked_by_size() {
+        let small: Matrix<u8, 2, 3> = Matrix::default();
+        let large: Matrix<u8, 2, 6000> = Matrix::default();
+
+        assert!(small.iter().all(|row| matches!(row, Vector::Small(_))));
+        assert!(large.iter().all(|row| matches!(row, Vector::Large(_))));
+    }
+
+    #[test]
+    #[should_panic(expected = "expected M * N = 6 row-major items, found 5")]
+    fn wrong_row_major_length() {
+        Storage::<u8, 2, 3>::from_row_major(&[0; 5]);
+    }
+
+    #[test]
+    fn wrong_row_count() {
+        let matrix: Result<Matrix<u8, 3, 2>, Error> =
+            Matrix::try_new(vec![Vector::new([1, 2]), Vector::new([3, 4])]);
+
+        assert_eq!(
+            matrix,
+            Err(Error::LengthMismatch {
+                expected: 3,
+                found: 2
+            })
+        );
+    }
+}
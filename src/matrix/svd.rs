@@ -0,0 +1,255 @@
+use num::{Float, FromPrimitive, ToPrimitive};
+use std::{
+    fmt::Debug,
+    ops::{Add, AddAssign, Mul, MulAssign, Sub, SubAssign},
+};
+
+use super::{jacobi, Matrix};
+
+/// Singular value decomposition `A = UΣVᵀ` of a `Matrix<T, M, N>`.
+///
+/// `u` and `v_t` are orthogonal while `singular_values` holds the `min(M, N)` diagonal entries of
+/// `Σ` in descending order.
+/// ```rust
+/// # use sickmath::*;
+/// let matrix: Matrix<f64, 2, 3> = Matrix::new([[3.0, 2.0, 2.0], [2.0, 3.0, -2.0]]);
+/// let svd = matrix.svd();
+///
+/// assert_eq!(svd.singular_values.len(), 2);
+/// assert!((svd.singular_values[0] - 5.0).abs() < 1e-12);
+/// assert!((svd.singular_values[1] - 3.0).abs() < 1e-12);
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct Svd<T, const M: usize, const N: usize> {
+    pub u: Matrix<T, M, M>,
+    pub singular_values: Vec<T>,
+    pub v_t: Matrix<T, N, N>,
+}
+
+impl<T, const M: usize, const N: usize> Matrix<T, M, N>
+where
+    T: Default
+        + Copy
+        + FromPrimitive
+        + ToPrimitive
+        + Mul<Output = T>
+        + MulAssign
+        + Add<Output = T>
+        + AddAssign
+        + Sub<Output = T>
+        + SubAssign
+        + Debug
+        + Float,
+{
+    /// Singular value decomposition using one-sided Jacobi rotations
+    pub fn svd(&self) -> Svd<T, M, N> {
+        let rank_dim = M.min(N);
+        let (mut u, singular_values, v) = jacobi::svd(&self.to_row_major(), M, N);
+
+        // Left singular vectors of negligible singular values are mostly rounding noise, so they
+        // are rebuilt from an orthogonal complement instead
+        let tolerance = tolerance::<T, M, N>(&singular_values);
+        for (idx, singular_value) in singular_values.iter().enumerate() {
+            if *singular_value <= tolerance {
+                for row in 0..M {
+                    u[row * rank_dim + idx] = T::zero();
+                }
+            }
+        }
+
+        let u = jacobi::complete_basis(&u, M, rank_dim);
+        let v = jacobi::complete_basis(&v, N, rank_dim);
+
+        let mut u_matrix: Matrix<T, M, M> = Matrix::default();
+        for row in 0..M {
+            for col in 0..M {
+                u_matrix[row][col] = u[row * M + col];
+            }
+        }
+
+        let mut v_t: Matrix<T, N, N> = Matrix::default();
+        for row in 0..N {
+            for col in 0..N {
+                v_t[col][row] = v[row * N + col];
+            }
+        }
+
+        Svd {
+            u: u_matrix,
+            singular_values,
+            v_t,
+        }
+    }
+
+    /// Number of singular values above `max(M, N) * ε * σ_max`
+    pub fn rank(&self) -> usize {
+        self.svd().rank()
+    }
+
+    /// Ratio between the largest and the smallest singular value
+    pub fn condition_number(&self) -> T {
+        self.svd().condition_number()
+    }
+}
+
+impl<T, const M: usize, const N: usize> Svd<T, M, N>
+where
+    T: Default
+        + Copy
+        + FromPrimitive
+        + ToPrimitive
+        + Mul<Output = T>
+        + MulAssign
+        + Add<Output = T>
+        + AddAssign
+        + Sub<Output = T>
+        + SubAssign
+        + Debug
+        + Float,
+{
+    /// Number of singular values above `max(M, N) * ε * σ_max`
+    pub fn rank(&self) -> usize {
+        let tolerance = tolerance::<T, M, N>(&self.singular_values);
+
+        self.singular_values
+            .iter()
+            .filter(|singular_value| **singular_value > tolerance)
+            .count()
+    }
+
+    /// Ratio between the largest and the smallest singular value. Infinite for a rank deficient
+    /// matrix, meaning one whose smallest singular value is within the tolerance of `rank`
+    pub fn condition_number(&self) -> T {
+        match (self.singular_values.first(), self.singular_values.last()) {
+            (Some(_), Some(smallest))
+                if *smallest <= tolerance::<T, M, N>(&self.singular_values) =>
+            {
+                T::infinity()
+            }
+            (Some(largest), Some(smallest)) => *largest / *smallest,
+            _ => T::zero(),
+        }
+    }
+
+    /// Best approximation of the original matrix with at most `rank` singular values, in both the
+    /// spectral and Frobenius norm
+    pub fn low_rank_approximation(&self, rank: usize) -> Matrix<T, M, N> {
+        let mut approximation: Matrix<T, M, N> = Matrix::default();
+
+        for (idx, singular_value) in self.singular_values.iter().take(rank).enumerate() {
+            for row in 0..M {
+                let scaled = self.u[row][idx] * *singular_value;
+                for col in 0..N {
+                    approximation[row][col] += scaled * self.v_t[idx][col];
+                }
+            }
+        }
+
+        approximation
+    }
+}
+
+fn tolerance<T, const M: usize, const N: usize>(singular_values: &[T]) -> T
+where
+    T: Float + FromPrimitive,
+{
+    let largest = singular_values.first().copied().unwrap_or_else(T::zero);
+
+    largest * T::epsilon() * T::from_usize(M.max(N)).unwrap_or_else(T::one)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::Matrix;
+
+    fn assert_close<const M: usize, const N: usize>(a: &Matrix<f64, M, N>, b: &Matrix<f64, M, N>) {
+        for row in 0..M {
+            for col in 0..N {
+                assert!(
+                    (a[row][col] - b[row][col]).abs() < 1e-10,
+                    "{:?} is not close to {:?}",
+                    a,
+                    b
+                );
+            }
+        }
+    }
+
+    fn sigma<const M: usize, const N: usize>(singular_values: &[f64]) -> Matrix<f64, M, N> {
+        let mut sigma: Matrix<f64, M, N> = Matrix::default();
+        for (idx, singular_value) in singular_values.iter().enumerate() {
+            sigma[idx][idx] = *singular_value;
+        }
+
+        sigma
+    }
+
+    #[test]
+    fn svd_tall_matrix() {
+        let matrix: Matrix<f64, 4, 2> =
+            Matrix::new([[1.0, 2.0], [3.0, 4.0], [5.0, 6.0], [7.0, 8.0]]);
+
+        let svd = matrix.svd();
+
+        assert_close(&svd.u.transpose().mult(&svd.u), &Matrix::identity());
+        assert_close(&svd.v_t.mult(&svd.v_t.transpose()), &Matrix::identity());
+        assert_close(
+            &svd.u
+                .mult(&sigma::<4, 2>(&svd.singular_values))
+                .mult(&svd.v_t),
+            &matrix,
+        );
+    }
+
+    #[test]
+    fn svd_wide_rank_deficient_matrix() {
+        let matrix: Matrix<f64, 2, 3> = Matrix::new([[1.0, 2.0, 3.0], [2.0, 4.0, 6.0]]);
+
+        let svd = matrix.svd();
+
+        assert_eq!(svd.rank(), 1);
+        assert_eq!(matrix.condition_number(), f64::INFINITY);
+        assert_close(&svd.u.transpose().mult(&svd.u), &Matrix::identity());
+        assert_close(&svd.v_t.mult(&svd.v_t.transpose()), &Matrix::identity());
+        assert_close(
+            &svd.u
+                .mult(&sigma::<2, 3>(&svd.singular_values))
+                .mult(&svd.v_t),
+            &matrix,
+        );
+    }
+
+    #[test]
+    fn svd_condition_number() {
+        let matrix: Matrix<f64, 2, 2> = Matrix::new([[4.0, 0.0], [0.0, -2.0]]);
+
+        assert!((matrix.condition_number() - 2.0).abs() < 1e-12);
+        assert_eq!(matrix.rank(), 2);
+
+        // The third singular value is rounding noise rather than exactly zero
+        let rank_deficient: Matrix<f64, 3, 3> =
+            Matrix::new([[1.0, 2.0, 3.0], [4.0, 5.0, 6.0], [7.0, 8.0, 9.0]]);
+        let svd = rank_deficient.svd();
+
+        assert_ne!(svd.singular_values[2], 0.0);
+        assert_eq!(svd.rank(), 2);
+        assert_eq!(svd.condition_number(), f64::INFINITY);
+        assert_eq!(
+            Matrix::<f64, 2, 2>::default().condition_number(),
+            f64::INFINITY
+        );
+    }
+
+    #[test]
+    fn svd_low_rank_approximation() {
+        let matrix: Matrix<f64, 3, 3> =
+            Matrix::new([[3.0, 0.0, 0.0], [0.0, 2.0, 0.0], [0.0, 0.0, 1.0]]);
+
+        let approximation = matrix.svd().low_rank_approximation(2);
+
+        assert_close(
+            &approximation,
+            &Matrix::new([[3.0, 0.0, 0.0], [0.0, 2.0, 0.0], [0.0, 0.0, 0.0]]),
+        );
+    }
+}
@@ -2,23 +2,25 @@ use core::fmt;
 
 use num::FromPrimitive;
 
-use crate::vector::Vector;
+use crate::parallel::{for_each_row_block, ParallelElement};
 
 use super::Matrix;
 
 impl<T, const M: usize, const N: usize> Matrix<T, M, N>
 where
-    T: FromPrimitive + fmt::Debug + Copy,
+    T: Default + FromPrimitive + fmt::Debug + Copy + ParallelElement,
 {
     pub fn transpose(&self) -> Matrix<T, N, M> {
-        let mut transposed: [Vector<T, M>; N] = unsafe { std::mem::zeroed() };
+        let mut transposed: Matrix<T, N, M> = Matrix::default();
 
-        for (idx_row, row) in self.iter().enumerate() {
-            for (idx_col, num) in row.iter().enumerate() {
-                transposed[idx_col][idx_row] = *num;
+        for_each_row_block(&mut transposed.inner, M * N, |first_row, rows| {
+            for (idx_row, row) in rows.iter_mut().enumerate() {
+                for (idx_col, num) in row.iter_mut().enumerate() {
+                    *num = self[idx_col][first_row + idx_row];
+                }
             }
-        }
+        });
 
-        Matrix { inner: transposed }
+        transposed
     }
 }
@@ -0,0 +1,159 @@
+#[cfg(feature = "parallel")]
+use std::{
+    num::NonZeroUsize,
+    sync::atomic::{AtomicUsize, Ordering},
+    thread,
+};
+
+#[cfg(feature = "parallel")]
+static NUM_THREADS: AtomicUsize = AtomicUsize::new(0);
+
+/// Below this many scalar operations spawning threads costs more than it saves
+#[cfg(feature = "parallel")]
+const MIN_PARALLEL_WORK: usize = 1 << 15;
+
+/// Sets how many threads matrix operations split their rows across.
+///
+/// `0` restores the default, which is the number of cores reported by the operating system.
+/// ```rust
+/// # use sickmath::*;
+/// set_num_threads(4);
+/// assert_eq!(num_threads(), 4);
+/// ```
+#[cfg(feature = "parallel")]
+pub fn set_num_threads(threads: usize) {
+    NUM_THREADS.store(threads, Ordering::Relaxed);
+}
+
+/// Number of threads matrix operations split their rows across
+#[cfg(feature = "parallel")]
+pub fn num_threads() -> usize {
+    match NUM_THREADS.load(Ordering::Relaxed) {
+        0 => thread::available_parallelism().map_or(1, NonZeroUsize::get),
+        threads => threads,
+    }
+}
+
+/// Element types that matrix operations can split across threads.
+///
+/// Every type implements it. With the `parallel` feature it requires `Send + Sync`, without it
+/// there is no bound, so the serial build doesn't put thread safety bounds on matrix arithmetic.
+pub trait ParallelElement: bound::ThreadSafe {}
+
+impl<T: bound::ThreadSafe> ParallelElement for T {}
+
+mod bound {
+    /// The bound behind `ParallelElement`, in a private module so it can't be named outside
+    #[cfg(feature = "parallel")]
+    pub trait ThreadSafe: Send + Sync {}
+
+    #[cfg(feature = "parallel")]
+    impl<T: Send + Sync> ThreadSafe for T {}
+
+    #[cfg(not(feature = "parallel"))]
+    pub trait ThreadSafe {}
+
+    #[cfg(not(feature = "parallel"))]
+    impl<T> ThreadSafe for T {}
+}
+
+/// Runs `work` over consecutive blocks of `rows`, passing the index of the first row in each block.
+///
+/// Every block gets its own scoped thread, unless `cost` is too small to be worth it. Each row is
+/// only ever written by one call of `work`, so the result is the same as running it serially.
+#[cfg(feature = "parallel")]
+pub(crate) fn for_each_row_block<R: Send>(
+    rows: &mut [R],
+    cost: usize,
+    work: impl Fn(usize, &mut [R]) + Sync,
+) {
+    for_each_row_block_with(threads(), rows, cost, work);
+}
+
+#[cfg(all(feature = "parallel", not(test)))]
+fn threads() -> usize {
+    num_threads()
+}
+
+/// Tests set the thread count of their own thread instead of the global every other test runs
+/// under
+#[cfg(all(feature = "parallel", test))]
+fn threads() -> usize {
+    tests::TEST_THREADS
+        .with(std::cell::Cell::get)
+        .unwrap_or_else(num_threads)
+}
+
+/// `for_each_row_block` across at most `threads` threads
+#[cfg(feature = "parallel")]
+fn for_each_row_block_with<R: Send>(
+    threads: usize,
+    rows: &mut [R],
+    cost: usize,
+    work: impl Fn(usize, &mut [R]) + Sync,
+) {
+    let threads = threads.min(rows.len());
+
+    if threads > 1 && cost >= MIN_PARALLEL_WORK {
+        let block_size = rows.len().div_ceil(threads);
+        let work = &work;
+
+        thread::scope(|scope| {
+            for (block, block_rows) in rows.chunks_mut(block_size).enumerate() {
+                scope.spawn(move || work(block * block_size, block_rows));
+            }
+        });
+    } else {
+        work(0, rows);
+    }
+}
+
+/// Runs `work` over all of `rows` at once, the serial counterpart of the threaded version
+#[cfg(not(feature = "parallel"))]
+pub(crate) fn for_each_row_block<R>(rows: &mut [R], _cost: usize, work: impl Fn(usize, &mut [R])) {
+    work(0, rows);
+}
+
+#[cfg(all(test, feature = "parallel"))]
+mod tests {
+    use super::*;
+    use crate::Matrix;
+    use std::cell::Cell;
+
+    thread_local! {
+        /// Thread count for matrix operations on the current test thread
+        pub(super) static TEST_THREADS: Cell<Option<usize>> = const { Cell::new(None) };
+    }
+
+    #[test]
+    fn row_blocks_cover_every_row_once() {
+        for threads in [1, 3, 10, 20] {
+            let mut rows = vec![0; 10];
+            for_each_row_block_with(threads, &mut rows, MIN_PARALLEL_WORK, |first_row, block| {
+                for (idx, row) in block.iter_mut().enumerate() {
+                    *row += first_row + idx;
+                }
+            });
+
+            assert_eq!(rows, (0..10).collect::<Vec<usize>>());
+        }
+    }
+
+    #[test]
+    fn parallel_matches_serial() {
+        let matrix_1: Matrix<f64, 64, 600> = Matrix::new_random();
+        let matrix_2: Matrix<f64, 600, 64> = Matrix::new_random();
+        let matrix_3: Matrix<f64, 64, 600> = Matrix::new_random();
+        let run = |threads| {
+            TEST_THREADS.with(|cell| cell.set(Some(threads)));
+            (
+                matrix_1.mult(&matrix_2),
+                matrix_1.add(&matrix_3),
+                matrix_1.subtract(&matrix_3),
+                matrix_1.transpose(),
+            )
+        };
+
+        assert_eq!(run(1), run(5));
+    }
+}
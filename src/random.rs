@@ -0,0 +1,160 @@
+use std::f64::consts::PI;
+
+use num::{Float, One, Zero};
+use rand::{distributions::Distribution, Rng, SeedableRng};
+use rand_chacha::ChaCha8Rng;
+
+use crate::error::Error;
+
+/// Generator behind the `from_seed` constructors.
+///
+/// Unlike `rand::rngs::StdRng`, ChaCha8 is guaranteed to produce the same stream for a seed across
+/// releases and platforms, so seeded experiments stay reproducible.
+pub(crate) fn seeded_rng(seed: u64) -> ChaCha8Rng {
+    ChaCha8Rng::seed_from_u64(seed)
+}
+
+/// Normal distribution with a given mean and standard deviation.
+///
+/// Samples are drawn with the Box–Muller transform, using one uniform `f64` pair per sample.
+/// ```rust
+/// # use sickmath::*;
+/// let mut rng = rand::thread_rng();
+/// let normal = Normal::new(0.0, 0.1).unwrap();
+/// let noise: Vector<f64, 4> = Vector::from_distribution(&normal, &mut rng);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Normal<T> {
+    mean: T,
+    std_dev: T,
+}
+
+impl<T> Normal<T>
+where
+    T: Float,
+{
+    /// Fails with `InvalidDistribution` if `std_dev` is negative or either parameter isn't finite
+    pub fn new(mean: T, std_dev: T) -> Result<Self, Error> {
+        if !mean.is_finite() || !std_dev.is_finite() || std_dev < T::zero() {
+            return Err(Error::InvalidDistribution);
+        }
+
+        Ok(Normal { mean, std_dev })
+    }
+
+    pub fn mean(&self) -> T {
+        self.mean
+    }
+
+    pub fn std_dev(&self) -> T {
+        self.std_dev
+    }
+}
+
+impl<T> Distribution<T> for Normal<T>
+where
+    T: Float,
+{
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> T {
+        // `gen` is in [0, 1), so `1 - gen` keeps the logarithm finite
+        let radius = (-2.0 * (1.0 - rng.gen::<f64>()).ln()).sqrt();
+        let angle = 2.0 * PI * rng.gen::<f64>();
+
+        self.mean + self.std_dev * T::from(radius * angle.cos()).unwrap()
+    }
+}
+
+/// Yields one with probability `p` and zero otherwise, for example to build dropout masks
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Bernoulli {
+    p: f64,
+}
+
+impl Bernoulli {
+    /// Fails with `InvalidDistribution` if `p` is outside of `[0, 1]`
+    pub fn new(p: f64) -> Result<Self, Error> {
+        if !(0.0..=1.0).contains(&p) {
+            return Err(Error::InvalidDistribution);
+        }
+
+        Ok(Bernoulli { p })
+    }
+
+    pub fn p(&self) -> f64 {
+        self.p
+    }
+}
+
+impl<T> Distribution<T> for Bernoulli
+where
+    T: Zero + One,
+{
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> T {
+        if rng.gen::<f64>() < self.p {
+            T::one()
+        } else {
+            T::zero()
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::seeded_rng;
+    use crate::{Bernoulli, DMatrix, Error, Matrix, Normal, Vector};
+
+    #[test]
+    fn small_and_large_rows_match() {
+        let small: Vector<u32, 64> = Vector::new_random_with(&mut seeded_rng(3));
+        let large: Vector<u32, 64> = Vector::new_large_random_with(&mut seeded_rng(3));
+
+        assert_eq!(small, large);
+    }
+
+    #[test]
+    fn large_matrix_rows_are_reproducible() {
+        let matrix: Matrix<f32, 2, 6000> = Matrix::from_seed(11);
+        let dynamic: DMatrix<f32> = DMatrix::new_random_with(2, 6000, &mut seeded_rng(11));
+
+        assert!(matches!(matrix[0], Vector::Large(_)));
+        assert_eq!(DMatrix::from(matrix), dynamic);
+    }
+
+    #[test]
+    fn seeds_give_different_matrices() {
+        let matrix: Matrix<f64, 4, 4> = Matrix::from_seed(0);
+
+        assert_eq!(matrix, Matrix::from_seed(0));
+        assert_ne!(matrix, Matrix::from_seed(1));
+    }
+
+    #[test]
+    fn normal_moments() {
+        let normal = Normal::new(2.0, 0.5).unwrap();
+        let samples: Vector<f64, 20000> = Vector::from_distribution(&normal, &mut seeded_rng(5));
+
+        let mean = samples.iter().sum::<f64>() / 20000.0;
+        let variance = samples.iter().map(|num| (num - mean).powi(2)).sum::<f64>() / 20000.0;
+
+        assert!((mean - 2.0).abs() < 0.02, "{}", mean);
+        assert!((variance - 0.25).abs() < 0.01, "{}", variance);
+    }
+
+    #[test]
+    fn bernoulli_rate() {
+        let bernoulli = Bernoulli::new(0.3).unwrap();
+        let mask: Matrix<u32, 100, 100> = Matrix::from_distribution(&bernoulli, &mut seeded_rng(9));
+
+        let ones: u32 = mask.iter().map(|row| row.iter().sum::<u32>()).sum();
+
+        assert!((2800..3200).contains(&ones), "{}", ones);
+    }
+
+    #[test]
+    fn invalid_parameters() {
+        assert_eq!(Normal::new(0.0, -1.0), Err(Error::InvalidDistribution));
+        assert_eq!(Normal::new(f64::NAN, 1.0), Err(Error::InvalidDistribution));
+        assert_eq!(Bernoulli::new(1.5), Err(Error::InvalidDistribution));
+        assert_eq!(Bernoulli::new(f64::NAN), Err(Error::InvalidDistribution));
+    }
+}
@@ -0,0 +1,328 @@
+//! Vectorized kernels for the element types that have them.
+//!
+//! Every function first checks whether `T` is `f32`, `f64` or `i32` and hands the slices to
+//! the fastest kernel the CPU supports, picked at runtime. When there is no kernel for `T` or
+//! the target, the function does nothing and reports it, so the caller can fall back to its
+//! generic loop. `T` doesn't have to be `'static`: a type that borrows is never one of the kernel
+//! types and always takes the generic loop.
+//!
+//! The `i32` kernels wrap on overflow, so they're only used in builds without debug assertions,
+//! where the generic loop wraps as well. With debug assertions `i32` always takes the generic
+//! loop, which panics on overflow like any other integer arithmetic.
+
+#[cfg(target_arch = "x86_64")]
+mod x86_64;
+
+#[cfg(target_arch = "x86_64")]
+use std::{any::TypeId, marker::PhantomData, mem};
+
+/// Whether the wrapping `i32` kernels agree with the overflow behavior of the build
+#[cfg(target_arch = "x86_64")]
+const I32_KERNELS: bool = !cfg!(debug_assertions);
+
+/// Adds `rhs` to `dst` in place. Returns `false` if no kernel exists for `T`
+pub(crate) fn add_assign<T>(dst: &mut [T], rhs: &[T]) -> bool {
+    assert_eq!(dst.len(), rhs.len());
+
+    #[cfg(target_arch = "x86_64")]
+    {
+        if let (Some(dst), Some(rhs)) = (cast_mut::<T, f64>(dst), cast::<T, f64>(rhs)) {
+            x86_64::add_assign_f64(dst, rhs);
+            return true;
+        }
+        if let (Some(dst), Some(rhs)) = (cast_mut::<T, f32>(dst), cast::<T, f32>(rhs)) {
+            x86_64::add_assign_f32(dst, rhs);
+            return true;
+        }
+        if I32_KERNELS {
+            if let (Some(dst), Some(rhs)) = (cast_mut::<T, i32>(dst), cast::<T, i32>(rhs)) {
+                x86_64::add_assign_i32(dst, rhs);
+                return true;
+            }
+        }
+    }
+
+    false
+}
+
+/// Subtracts `rhs` from `dst` in place. Returns `false` if no kernel exists for `T`
+pub(crate) fn sub_assign<T>(dst: &mut [T], rhs: &[T]) -> bool {
+    assert_eq!(dst.len(), rhs.len());
+
+    #[cfg(target_arch = "x86_64")]
+    {
+        if let (Some(dst), Some(rhs)) = (cast_mut::<T, f64>(dst), cast::<T, f64>(rhs)) {
+            x86_64::sub_assign_f64(dst, rhs);
+            return true;
+        }
+        if let (Some(dst), Some(rhs)) = (cast_mut::<T, f32>(dst), cast::<T, f32>(rhs)) {
+            x86_64::sub_assign_f32(dst, rhs);
+            return true;
+        }
+        if I32_KERNELS {
+            if let (Some(dst), Some(rhs)) = (cast_mut::<T, i32>(dst), cast::<T, i32>(rhs)) {
+                x86_64::sub_assign_i32(dst, rhs);
+                return true;
+            }
+        }
+    }
+
+    false
+}
+
+/// Multiplies `dst` entrywise with `rhs` in place. Returns `false` if no kernel exists for `T`
+pub(crate) fn mul_assign<T>(dst: &mut [T], rhs: &[T]) -> bool {
+    assert_eq!(dst.len(), rhs.len());
+
+    #[cfg(target_arch = "x86_64")]
+    {
+        if let (Some(dst), Some(rhs)) = (cast_mut::<T, f64>(dst), cast::<T, f64>(rhs)) {
+            x86_64::mul_assign_f64(dst, rhs);
+            return true;
+        }
+        if let (Some(dst), Some(rhs)) = (cast_mut::<T, f32>(dst), cast::<T, f32>(rhs)) {
+            x86_64::mul_assign_f32(dst, rhs);
+            return true;
+        }
+        if I32_KERNELS {
+            if let (Some(dst), Some(rhs)) = (cast_mut::<T, i32>(dst), cast::<T, i32>(rhs)) {
+                return x86_64::mul_assign_i32(dst, rhs);
+            }
+        }
+    }
+
+    false
+}
+
+/// Multiplies every item of `dst` with `scalar`. Returns `false` if no kernel exists for `T`
+#[cfg_attr(not(target_arch = "x86_64"), allow(unused_variables))]
+pub(crate) fn scale<T: Copy>(dst: &mut [T], scalar: T) -> bool {
+    #[cfg(target_arch = "x86_64")]
+    {
+        if let (Some(dst), Some(scalar)) = (cast_mut::<T, f64>(dst), cast_value::<T, f64>(scalar)) {
+            x86_64::scale_f64(dst, scalar);
+            return true;
+        }
+        if let (Some(dst), Some(scalar)) = (cast_mut::<T, f32>(dst), cast_value::<T, f32>(scalar)) {
+            x86_64::scale_f32(dst, scalar);
+            return true;
+        }
+        if I32_KERNELS {
+            if let (Some(dst), Some(scalar)) =
+                (cast_mut::<T, i32>(dst), cast_value::<T, i32>(scalar))
+            {
+                return x86_64::scale_i32(dst, scalar);
+            }
+        }
+    }
+
+    false
+}
+
+/// Dot product of `lhs` and `rhs`. Returns `None` if no kernel exists for `T`
+pub(crate) fn dot<T: Copy>(lhs: &[T], rhs: &[T]) -> Option<T> {
+    assert_eq!(lhs.len(), rhs.len());
+
+    #[cfg(target_arch = "x86_64")]
+    {
+        if let (Some(lhs), Some(rhs)) = (cast::<T, f64>(lhs), cast::<T, f64>(rhs)) {
+            return cast_result(x86_64::dot_f64(lhs, rhs));
+        }
+        if let (Some(lhs), Some(rhs)) = (cast::<T, f32>(lhs), cast::<T, f32>(rhs)) {
+            return cast_result(x86_64::dot_f32(lhs, rhs));
+        }
+        if I32_KERNELS {
+            if let (Some(lhs), Some(rhs)) = (cast::<T, i32>(lhs), cast::<T, i32>(rhs)) {
+                return x86_64::dot_i32(lhs, rhs).and_then(cast_result);
+            }
+        }
+    }
+
+    None
+}
+
+/// Whether `T` is the kernel type `U`.
+///
+/// `TypeId::of` needs `T: 'static`, so the `TypeId` is read through a trait object whose lifetime
+/// is extended to `'static`, which is what the `typeid` crate does as well.
+#[cfg(target_arch = "x86_64")]
+fn is<T, U: 'static>() -> bool {
+    trait NonStaticAny {
+        fn get_type_id(&self) -> TypeId
+        where
+            Self: 'static;
+    }
+
+    impl<T> NonStaticAny for PhantomData<T> {
+        fn get_type_id(&self) -> TypeId
+        where
+            Self: 'static,
+        {
+            TypeId::of::<T>()
+        }
+    }
+
+    let phantom = PhantomData::<T>;
+    // SAFETY: only the `TypeId` is read through the extended lifetime. It doesn't depend on
+    // lifetimes, and a type that borrows for less than `'static` can never equal `U: 'static`
+    let phantom =
+        unsafe { mem::transmute::<&dyn NonStaticAny, &(dyn NonStaticAny + 'static)>(&phantom) };
+
+    phantom.get_type_id() == TypeId::of::<U>()
+}
+
+#[cfg(target_arch = "x86_64")]
+fn cast<T, U: 'static>(slice: &[T]) -> Option<&[U]> {
+    if is::<T, U>() {
+        // SAFETY: `T` and `U` are the same type
+        Some(unsafe { std::slice::from_raw_parts(slice.as_ptr() as *const U, slice.len()) })
+    } else {
+        None
+    }
+}
+
+#[cfg(target_arch = "x86_64")]
+fn cast_mut<T, U: 'static>(slice: &mut [T]) -> Option<&mut [U]> {
+    if is::<T, U>() {
+        // SAFETY: `T` and `U` are the same type
+        Some(unsafe { std::slice::from_raw_parts_mut(slice.as_mut_ptr() as *mut U, slice.len()) })
+    } else {
+        None
+    }
+}
+
+#[cfg(target_arch = "x86_64")]
+fn cast_value<T: Copy, U: Copy + 'static>(value: T) -> Option<U> {
+    if is::<T, U>() {
+        // SAFETY: `T` and `U` are the same type
+        Some(unsafe { mem::transmute_copy::<T, U>(&value) })
+    } else {
+        None
+    }
+}
+
+/// Turns the result of a kernel back into `T`
+#[cfg(target_arch = "x86_64")]
+fn cast_result<U: Copy + 'static, T: Copy>(value: U) -> Option<T> {
+    if is::<T, U>() {
+        // SAFETY: `T` and `U` are the same type
+        Some(unsafe { mem::transmute_copy::<U, T>(&value) })
+    } else {
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{MathVector, Vector};
+
+    fn sequence<T: num::FromPrimitive>(len: usize, offset: i32) -> Vec<T> {
+        (0..len)
+            .map(|idx| T::from_i32((idx as i32 * 7 + offset) % 23 - 11).unwrap())
+            .collect()
+    }
+
+    #[test]
+    #[cfg(target_arch = "x86_64")]
+    fn kernels_match_scalar_loops() {
+        // 37 isn't a multiple of any lane count, so the tails are covered as well
+        let lhs: Vec<f64> = sequence(37, 3);
+        let rhs: Vec<f64> = sequence(37, 5);
+
+        let mut added = lhs.clone();
+        assert!(add_assign(&mut added, &rhs));
+        let mut subtracted = lhs.clone();
+        assert!(sub_assign(&mut subtracted, &rhs));
+        let mut multiplied = lhs.clone();
+        assert!(mul_assign(&mut multiplied, &rhs));
+        let mut scaled = lhs.clone();
+        assert!(scale(&mut scaled, 1.5));
+
+        for idx in 0..37 {
+            assert_eq!(added[idx], lhs[idx] + rhs[idx]);
+            assert_eq!(subtracted[idx], lhs[idx] - rhs[idx]);
+            assert_eq!(multiplied[idx], lhs[idx] * rhs[idx]);
+            assert_eq!(scaled[idx], lhs[idx] * 1.5);
+        }
+
+        let expected: f64 = lhs.iter().zip(rhs.iter()).map(|(a, b)| a * b).sum();
+        assert_eq!(dot(&lhs, &rhs), Some(expected));
+    }
+
+    #[test]
+    #[cfg(target_arch = "x86_64")]
+    fn f32_dot_kernel() {
+        let lhs: Vec<f32> = sequence(19, 1);
+        let rhs: Vec<f32> = sequence(19, 2);
+        let expected: f32 = lhs.iter().zip(rhs.iter()).map(|(a, b)| a * b).sum();
+        assert_eq!(dot(&lhs, &rhs), Some(expected));
+    }
+
+    #[test]
+    #[cfg(target_arch = "x86_64")]
+    fn i32_kernels_wrap() {
+        // Every lane overflows in at least one of the operations, including the tail
+        let lhs: Vec<i32> = (0..21).map(|idx| i32::MAX - idx).collect();
+        let rhs: Vec<i32> = (0..21)
+            .map(|idx| if idx % 2 == 0 { idx * 3 + 1 } else { -idx * 3 })
+            .collect();
+        let wrapped = |f: fn(i32, i32) -> i32| -> Vec<i32> {
+            lhs.iter().zip(rhs.iter()).map(|(a, b)| f(*a, *b)).collect()
+        };
+
+        let mut added = lhs.clone();
+        x86_64::add_assign_i32(&mut added, &rhs);
+        assert_eq!(added, wrapped(i32::wrapping_add));
+
+        let mut subtracted = lhs.clone();
+        x86_64::sub_assign_i32(&mut subtracted, &rhs);
+        assert_eq!(subtracted, wrapped(i32::wrapping_sub));
+
+        // The remaining kernels depend on AVX2 and fall back to the caller otherwise
+        let mut multiplied = lhs.clone();
+        if x86_64::mul_assign_i32(&mut multiplied, &rhs) {
+            assert_eq!(multiplied, wrapped(i32::wrapping_mul));
+        }
+        if let Some(product) = x86_64::dot_i32(&lhs, &rhs) {
+            let expected = wrapped(i32::wrapping_mul)
+                .into_iter()
+                .fold(0, i32::wrapping_add);
+            assert_eq!(product, expected);
+        }
+    }
+
+    #[test]
+    #[cfg(target_arch = "x86_64")]
+    #[should_panic]
+    fn kernels_check_lengths() {
+        x86_64::add_assign_f64(&mut [1.0; 8], &[1.0; 3]);
+    }
+
+    #[test]
+    #[cfg_attr(debug_assertions, should_panic)]
+    fn i32_overflow_follows_the_build() {
+        let mut small: Vector<i32, 16> = Vector::new([i32::MAX; 16]);
+        let mut large: Vector<i32, 6000> = Vector::new_large(vec![i32::MAX; 6000]);
+
+        large.add_vector_mut(&Vector::new_large(vec![1; 6000]));
+        assert!(large.iter().all(|num| *num == i32::MIN));
+
+        small.add_vector_mut(&Vector::new([1; 16]));
+        assert_eq!(small, Vector::new([i32::MIN; 16]));
+    }
+
+    #[test]
+    fn no_kernel_for_other_types() {
+        let mut data: Vec<u8> = vec![1, 2, 3];
+
+        assert!(!add_assign(&mut data, &[1, 1, 1]));
+        assert!(!scale(&mut data, 2));
+        assert_eq!(dot(&data, &[1, 1, 1]), None);
+        assert_eq!(data, vec![1, 2, 3]);
+
+        let num = 1.0;
+        let mut borrowed: Vec<&f64> = vec![&num; 3];
+        assert!(!add_assign(&mut borrowed, &[&num; 3]));
+    }
+}
@@ -0,0 +1,504 @@
+//! SSE2, AVX2 and FMA kernels. SSE2 is part of the x86_64 baseline, everything else is detected
+//! at runtime before use.
+
+use std::arch::x86_64::*;
+
+/// `dst[i] = op(dst[i], rhs[i])`, `$lanes` items at a time
+macro_rules! elementwise_kernel {
+    ($name:ident, $feature:literal, $t:ty, $lanes:literal, $load:ident, $store:ident, $op:ident, $tail:path) => {
+        #[target_feature(enable = $feature)]
+        unsafe fn $name(dst: &mut [$t], rhs: &[$t]) {
+            let split = dst.len() - dst.len() % $lanes;
+
+            for idx in (0..split).step_by($lanes) {
+                let lhs_lanes = $load(dst.as_ptr().add(idx) as *const _);
+                let rhs_lanes = $load(rhs.as_ptr().add(idx) as *const _);
+                $store(
+                    dst.as_mut_ptr().add(idx) as *mut _,
+                    $op(lhs_lanes, rhs_lanes),
+                );
+            }
+
+            for (num, rhs_num) in dst[split..].iter_mut().zip(rhs[split..].iter()) {
+                *num = $tail(*num, *rhs_num);
+            }
+        }
+    };
+}
+
+/// `dst[i] = dst[i] * scalar`, `$lanes` items at a time
+macro_rules! scale_kernel {
+    ($name:ident, $feature:literal, $t:ty, $lanes:literal, $load:ident, $store:ident, $set1:ident, $mul:ident, $tail:path) => {
+        #[target_feature(enable = $feature)]
+        unsafe fn $name(dst: &mut [$t], scalar: $t) {
+            let split = dst.len() - dst.len() % $lanes;
+            let scalar_lanes = $set1(scalar);
+
+            for idx in (0..split).step_by($lanes) {
+                let lanes = $load(dst.as_ptr().add(idx) as *const _);
+                $store(
+                    dst.as_mut_ptr().add(idx) as *mut _,
+                    $mul(lanes, scalar_lanes),
+                );
+            }
+
+            for num in dst[split..].iter_mut() {
+                *num = $tail(*num, scalar);
+            }
+        }
+    };
+}
+
+/// Sum of `lhs[i] * rhs[i]`, accumulated in `$lanes` partial sums
+macro_rules! dot_kernel {
+    ($name:ident, $feature:literal, $t:ty, $lanes:literal, $load:ident, $store:ident, $zero:ident, |$acc:ident, $lhs:ident, $rhs:ident| $madd:expr, $tail_mul:path, $tail_add:path) => {
+        #[target_feature(enable = $feature)]
+        unsafe fn $name(lhs: &[$t], rhs: &[$t]) -> $t {
+            let split = lhs.len() - lhs.len() % $lanes;
+            let mut $acc = $zero();
+
+            for idx in (0..split).step_by($lanes) {
+                let $lhs = $load(lhs.as_ptr().add(idx) as *const _);
+                let $rhs = $load(rhs.as_ptr().add(idx) as *const _);
+                $acc = $madd;
+            }
+
+            let mut partial_sums = [<$t>::default(); $lanes];
+            $store(partial_sums.as_mut_ptr() as *mut _, $acc);
+
+            lhs[split..].iter().zip(rhs[split..].iter()).fold(
+                partial_sums
+                    .iter()
+                    .fold(<$t>::default(), |acc, num| $tail_add(acc, *num)),
+                |acc, (a, b)| $tail_add(acc, $tail_mul(*a, *b)),
+            )
+        }
+    };
+}
+
+use std::ops::{Add, Mul, Sub};
+
+elementwise_kernel!(
+    add_f64_sse2,
+    "sse2",
+    f64,
+    2,
+    _mm_loadu_pd,
+    _mm_storeu_pd,
+    _mm_add_pd,
+    Add::add
+);
+elementwise_kernel!(
+    add_f64_avx2,
+    "avx2",
+    f64,
+    4,
+    _mm256_loadu_pd,
+    _mm256_storeu_pd,
+    _mm256_add_pd,
+    Add::add
+);
+elementwise_kernel!(
+    add_f32_sse2,
+    "sse2",
+    f32,
+    4,
+    _mm_loadu_ps,
+    _mm_storeu_ps,
+    _mm_add_ps,
+    Add::add
+);
+elementwise_kernel!(
+    add_f32_avx2,
+    "avx2",
+    f32,
+    8,
+    _mm256_loadu_ps,
+    _mm256_storeu_ps,
+    _mm256_add_ps,
+    Add::add
+);
+elementwise_kernel!(
+    add_i32_sse2,
+    "sse2",
+    i32,
+    4,
+    _mm_loadu_si128,
+    _mm_storeu_si128,
+    _mm_add_epi32,
+    i32::wrapping_add
+);
+elementwise_kernel!(
+    add_i32_avx2,
+    "avx2",
+    i32,
+    8,
+    _mm256_loadu_si256,
+    _mm256_storeu_si256,
+    _mm256_add_epi32,
+    i32::wrapping_add
+);
+
+elementwise_kernel!(
+    sub_f64_sse2,
+    "sse2",
+    f64,
+    2,
+    _mm_loadu_pd,
+    _mm_storeu_pd,
+    _mm_sub_pd,
+    Sub::sub
+);
+elementwise_kernel!(
+    sub_f64_avx2,
+    "avx2",
+    f64,
+    4,
+    _mm256_loadu_pd,
+    _mm256_storeu_pd,
+    _mm256_sub_pd,
+    Sub::sub
+);
+elementwise_kernel!(
+    sub_f32_sse2,
+    "sse2",
+    f32,
+    4,
+    _mm_loadu_ps,
+    _mm_storeu_ps,
+    _mm_sub_ps,
+    Sub::sub
+);
+elementwise_kernel!(
+    sub_f32_avx2,
+    "avx2",
+    f32,
+    8,
+    _mm256_loadu_ps,
+    _mm256_storeu_ps,
+    _mm256_sub_ps,
+    Sub::sub
+);
+elementwise_kernel!(
+    sub_i32_sse2,
+    "sse2",
+    i32,
+    4,
+    _mm_loadu_si128,
+    _mm_storeu_si128,
+    _mm_sub_epi32,
+    i32::wrapping_sub
+);
+elementwise_kernel!(
+    sub_i32_avx2,
+    "avx2",
+    i32,
+    8,
+    _mm256_loadu_si256,
+    _mm256_storeu_si256,
+    _mm256_sub_epi32,
+    i32::wrapping_sub
+);
+
+elementwise_kernel!(
+    mul_f64_sse2,
+    "sse2",
+    f64,
+    2,
+    _mm_loadu_pd,
+    _mm_storeu_pd,
+    _mm_mul_pd,
+    Mul::mul
+);
+elementwise_kernel!(
+    mul_f64_avx2,
+    "avx2",
+    f64,
+    4,
+    _mm256_loadu_pd,
+    _mm256_storeu_pd,
+    _mm256_mul_pd,
+    Mul::mul
+);
+elementwise_kernel!(
+    mul_f32_sse2,
+    "sse2",
+    f32,
+    4,
+    _mm_loadu_ps,
+    _mm_storeu_ps,
+    _mm_mul_ps,
+    Mul::mul
+);
+elementwise_kernel!(
+    mul_f32_avx2,
+    "avx2",
+    f32,
+    8,
+    _mm256_loadu_ps,
+    _mm256_storeu_ps,
+    _mm256_mul_ps,
+    Mul::mul
+);
+elementwise_kernel!(
+    mul_i32_avx2,
+    "avx2",
+    i32,
+    8,
+    _mm256_loadu_si256,
+    _mm256_storeu_si256,
+    _mm256_mullo_epi32,
+    i32::wrapping_mul
+);
+
+scale_kernel!(
+    scale_f64_sse2,
+    "sse2",
+    f64,
+    2,
+    _mm_loadu_pd,
+    _mm_storeu_pd,
+    _mm_set1_pd,
+    _mm_mul_pd,
+    Mul::mul
+);
+scale_kernel!(
+    scale_f64_avx2,
+    "avx2",
+    f64,
+    4,
+    _mm256_loadu_pd,
+    _mm256_storeu_pd,
+    _mm256_set1_pd,
+    _mm256_mul_pd,
+    Mul::mul
+);
+scale_kernel!(
+    scale_f32_sse2,
+    "sse2",
+    f32,
+    4,
+    _mm_loadu_ps,
+    _mm_storeu_ps,
+    _mm_set1_ps,
+    _mm_mul_ps,
+    Mul::mul
+);
+scale_kernel!(
+    scale_f32_avx2,
+    "avx2",
+    f32,
+    8,
+    _mm256_loadu_ps,
+    _mm256_storeu_ps,
+    _mm256_set1_ps,
+    _mm256_mul_ps,
+    Mul::mul
+);
+scale_kernel!(
+    scale_i32_avx2,
+    "avx2",
+    i32,
+    8,
+    _mm256_loadu_si256,
+    _mm256_storeu_si256,
+    _mm256_set1_epi32,
+    _mm256_mullo_epi32,
+    i32::wrapping_mul
+);
+
+dot_kernel!(
+    dot_f64_sse2,
+    "sse2",
+    f64,
+    2,
+    _mm_loadu_pd,
+    _mm_storeu_pd,
+    _mm_setzero_pd,
+    |acc, lhs, rhs| _mm_add_pd(acc, _mm_mul_pd(lhs, rhs)),
+    Mul::mul,
+    Add::add
+);
+dot_kernel!(
+    dot_f64_avx2,
+    "avx2",
+    f64,
+    4,
+    _mm256_loadu_pd,
+    _mm256_storeu_pd,
+    _mm256_setzero_pd,
+    |acc, lhs, rhs| _mm256_add_pd(acc, _mm256_mul_pd(lhs, rhs)),
+    Mul::mul,
+    Add::add
+);
+dot_kernel!(
+    dot_f64_fma,
+    "avx2,fma",
+    f64,
+    4,
+    _mm256_loadu_pd,
+    _mm256_storeu_pd,
+    _mm256_setzero_pd,
+    |acc, lhs, rhs| _mm256_fmadd_pd(lhs, rhs, acc),
+    Mul::mul,
+    Add::add
+);
+dot_kernel!(
+    dot_f32_sse2,
+    "sse2",
+    f32,
+    4,
+    _mm_loadu_ps,
+    _mm_storeu_ps,
+    _mm_setzero_ps,
+    |acc, lhs, rhs| _mm_add_ps(acc, _mm_mul_ps(lhs, rhs)),
+    Mul::mul,
+    Add::add
+);
+dot_kernel!(
+    dot_f32_avx2,
+    "avx2",
+    f32,
+    8,
+    _mm256_loadu_ps,
+    _mm256_storeu_ps,
+    _mm256_setzero_ps,
+    |acc, lhs, rhs| _mm256_add_ps(acc, _mm256_mul_ps(lhs, rhs)),
+    Mul::mul,
+    Add::add
+);
+dot_kernel!(
+    dot_f32_fma,
+    "avx2,fma",
+    f32,
+    8,
+    _mm256_loadu_ps,
+    _mm256_storeu_ps,
+    _mm256_setzero_ps,
+    |acc, lhs, rhs| _mm256_fmadd_ps(lhs, rhs, acc),
+    Mul::mul,
+    Add::add
+);
+dot_kernel!(
+    dot_i32_avx2,
+    "avx2",
+    i32,
+    8,
+    _mm256_loadu_si256,
+    _mm256_storeu_si256,
+    _mm256_setzero_si256,
+    |acc, lhs, rhs| _mm256_add_epi32(acc, _mm256_mullo_epi32(lhs, rhs)),
+    i32::wrapping_mul,
+    i32::wrapping_add
+);
+
+/// Picks the widest of two kernels the CPU supports.
+///
+/// The kernels read `rhs` at every index of `dst` without checking its length, so wrappers taking
+/// two slices assert that they have the same length before calling a kernel.
+macro_rules! dispatch {
+    ($name:ident($dst:ident: $dst_t:ty, $rhs:ident: &[$t:ty]), $avx2:ident, $sse2:ident) => {
+        pub(super) fn $name($dst: $dst_t, $rhs: &[$t]) {
+            assert_eq!($dst.len(), $rhs.len());
+
+            // SAFETY: both slices have the same length, AVX2 is detected at runtime and SSE2 is
+            // always available on x86_64
+            unsafe {
+                if is_x86_feature_detected!("avx2") {
+                    $avx2($dst, $rhs)
+                } else {
+                    $sse2($dst, $rhs)
+                }
+            }
+        }
+    };
+    ($name:ident($dst:ident: $dst_t:ty, $scalar:ident: $t:ty), $avx2:ident, $sse2:ident) => {
+        pub(super) fn $name($dst: $dst_t, $scalar: $t) {
+            // SAFETY: AVX2 is detected at runtime and SSE2 is always available on x86_64
+            unsafe {
+                if is_x86_feature_detected!("avx2") {
+                    $avx2($dst, $scalar)
+                } else {
+                    $sse2($dst, $scalar)
+                }
+            }
+        }
+    };
+}
+
+dispatch!(add_assign_f64(dst: &mut [f64], rhs: &[f64]), add_f64_avx2, add_f64_sse2);
+dispatch!(add_assign_f32(dst: &mut [f32], rhs: &[f32]), add_f32_avx2, add_f32_sse2);
+dispatch!(add_assign_i32(dst: &mut [i32], rhs: &[i32]), add_i32_avx2, add_i32_sse2);
+dispatch!(sub_assign_f64(dst: &mut [f64], rhs: &[f64]), sub_f64_avx2, sub_f64_sse2);
+dispatch!(sub_assign_f32(dst: &mut [f32], rhs: &[f32]), sub_f32_avx2, sub_f32_sse2);
+dispatch!(sub_assign_i32(dst: &mut [i32], rhs: &[i32]), sub_i32_avx2, sub_i32_sse2);
+dispatch!(mul_assign_f64(dst: &mut [f64], rhs: &[f64]), mul_f64_avx2, mul_f64_sse2);
+dispatch!(mul_assign_f32(dst: &mut [f32], rhs: &[f32]), mul_f32_avx2, mul_f32_sse2);
+dispatch!(scale_f64(dst: &mut [f64], scalar: f64), scale_f64_avx2, scale_f64_sse2);
+dispatch!(scale_f32(dst: &mut [f32], scalar: f32), scale_f32_avx2, scale_f32_sse2);
+
+/// `i32` multiplication needs AVX2, SSE2 has no packed 32 bit multiply
+pub(super) fn mul_assign_i32(dst: &mut [i32], rhs: &[i32]) -> bool {
+    assert_eq!(dst.len(), rhs.len());
+
+    if is_x86_feature_detected!("avx2") {
+        // SAFETY: both slices have the same length and AVX2 was detected above
+        unsafe { mul_i32_avx2(dst, rhs) };
+        return true;
+    }
+
+    false
+}
+
+pub(super) fn scale_i32(dst: &mut [i32], scalar: i32) -> bool {
+    if is_x86_feature_detected!("avx2") {
+        // SAFETY: AVX2 was detected above
+        unsafe { scale_i32_avx2(dst, scalar) };
+        return true;
+    }
+
+    false
+}
+
+pub(super) fn dot_i32(lhs: &[i32], rhs: &[i32]) -> Option<i32> {
+    assert_eq!(lhs.len(), rhs.len());
+
+    if is_x86_feature_detected!("avx2") {
+        // SAFETY: both slices have the same length and AVX2 was detected above
+        return Some(unsafe { dot_i32_avx2(lhs, rhs) });
+    }
+
+    None
+}
+
+pub(super) fn dot_f64(lhs: &[f64], rhs: &[f64]) -> f64 {
+    assert_eq!(lhs.len(), rhs.len());
+
+    // SAFETY: both slices have the same length and every feature is detected at runtime before
+    // its kernel runs
+    unsafe {
+        if is_x86_feature_detected!("avx2") && is_x86_feature_detected!("fma") {
+            dot_f64_fma(lhs, rhs)
+        } else if is_x86_feature_detected!("avx2") {
+            dot_f64_avx2(lhs, rhs)
+        } else {
+            dot_f64_sse2(lhs, rhs)
+        }
+    }
+}
+
+pub(super) fn dot_f32(lhs: &[f32], rhs: &[f32]) -> f32 {
+    assert_eq!(lhs.len(), rhs.len());
+
+    // SAFETY: both slices have the same length and every feature is detected at runtime before
+    // its kernel runs
+    unsafe {
+        if is_x86_feature_detected!("avx2") && is_x86_feature_detected!("fma") {
+            dot_f32_fma(lhs, rhs)
+        } else if is_x86_feature_detected!("avx2") {
+            dot_f32_avx2(lhs, rhs)
+        } else {
+            dot_f32_sse2(lhs, rhs)
+        }
+    }
+}
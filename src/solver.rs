@@ -0,0 +1,303 @@
+pub mod bicgstab;
+pub mod conjugate_gradient;
+pub mod gmres;
+
+pub use bicgstab::bicgstab;
+pub use conjugate_gradient::conjugate_gradient;
+pub use gmres::gmres;
+
+use std::{
+    fmt::Debug,
+    ops::{Add, AddAssign, Mul, MulAssign, Sub, SubAssign},
+};
+
+use num::{Float, FromPrimitive, ToPrimitive};
+
+use crate::{
+    error::Error,
+    math_vector::MathVector,
+    matrix::Matrix,
+    parallel::ParallelElement,
+    sparse::{CscMatrix, CsrMatrix},
+    vector::Vector,
+};
+
+/// A square linear map `A` on vectors of length `N`.
+///
+/// The iterative solvers only ever need `Ax`, so anything that can compute it can be solved
+/// without forming a matrix, including closures.
+/// ```rust
+/// # use sickmath::*;
+/// // 2x on every entry, without storing a matrix
+/// let double = |x: &Vector<f64, 2>| x.scalar(2.0);
+///
+/// let report = conjugate_gradient(&double, &Vector::new([2.0, 4.0]), &SolverOptions::default()).unwrap();
+/// assert!(report.converged);
+/// assert!((report.solution[1] - 2.0).abs() < 1e-10);
+/// ```
+pub trait LinearOperator<T, const N: usize> {
+    /// Computes `Ax`
+    fn apply(&self, vector: &Vector<T, N>) -> Vector<T, N>;
+
+    /// The diagonal of `A`, which the Jacobi preconditioner needs. `None` if it isn't known
+    fn diagonal(&self) -> Option<Vector<T, N>> {
+        None
+    }
+}
+
+/// Preconditioner applied by the iterative solvers
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Preconditioner {
+    /// Solve the system as is
+    None,
+    /// Scale by the inverse of the diagonal of the operator
+    Jacobi,
+}
+
+/// Settings shared by all iterative solvers.
+///
+/// A solver stops once `‖b - Ax‖ ≤ tolerance · ‖b‖` or after `max_iterations`.
+/// ```rust
+/// # use sickmath::*;
+/// let options: SolverOptions<f64> = SolverOptions {
+///     tolerance: 1e-6,
+///     preconditioner: Preconditioner::Jacobi,
+///     ..SolverOptions::default()
+/// };
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct SolverOptions<T> {
+    /// Relative residual at which the solution is accepted
+    pub tolerance: T,
+    pub max_iterations: usize,
+    pub preconditioner: Preconditioner,
+    /// Size of the Krylov basis before GMRES restarts. Ignored by the other solvers
+    pub restart: usize,
+}
+
+impl<T> Default for SolverOptions<T>
+where
+    T: Float,
+{
+    /// A tolerance of `1e-10`, 1000 iterations, no preconditioner and restarts after 30 iterations
+    fn default() -> Self {
+        SolverOptions {
+            tolerance: T::from(1e-10).unwrap_or_else(T::epsilon),
+            max_iterations: 1000,
+            preconditioner: Preconditioner::None,
+            restart: 30,
+        }
+    }
+}
+
+/// Outcome of an iterative solver
+#[derive(Debug, Clone, PartialEq)]
+pub struct SolveReport<T, const N: usize> {
+    /// The last iterate, which is the best available solution even when not converged
+    pub solution: Vector<T, N>,
+    pub iterations: usize,
+    /// Relative residual `‖b - Ax‖ / ‖b‖` of `solution`
+    pub residual: T,
+    /// Whether `residual` is within the tolerance
+    pub converged: bool,
+}
+
+impl<T, const N: usize> LinearOperator<T, N> for Matrix<T, N, N>
+where
+    T: Default
+        + Copy
+        + FromPrimitive
+        + ToPrimitive
+        + Mul<Output = T>
+        + MulAssign
+        + Add<Output = T>
+        + AddAssign
+        + Sub<Output = T>
+        + SubAssign
+        + Debug
+        + ParallelElement,
+{
+    fn apply(&self, vector: &Vector<T, N>) -> Vector<T, N> {
+        self.mul_vector(vector)
+    }
+
+    fn diagonal(&self) -> Option<Vector<T, N>> {
+        Some((0..N).map(|idx| self[idx][idx]).collect())
+    }
+}
+
+impl<T, const N: usize> LinearOperator<T, N> for CsrMatrix<T, N, N>
+where
+    T: Default
+        + Copy
+        + FromPrimitive
+        + ToPrimitive
+        + Mul<Output = T>
+        + MulAssign
+        + Add<Output = T>
+        + AddAssign
+        + Sub<Output = T>
+        + SubAssign
+        + Debug,
+{
+    fn apply(&self, vector: &Vector<T, N>) -> Vector<T, N> {
+        self.product(vector).into_iter().collect()
+    }
+
+    fn diagonal(&self) -> Option<Vector<T, N>> {
+        Some(
+            (0..N)
+                .map(|idx| self.get(idx, idx).copied().unwrap_or_default())
+                .collect(),
+        )
+    }
+}
+
+impl<T, const N: usize> LinearOperator<T, N> for CscMatrix<T, N, N>
+where
+    T: Default
+        + Copy
+        + FromPrimitive
+        + ToPrimitive
+        + Mul<Output = T>
+        + MulAssign
+        + Add<Output = T>
+        + AddAssign
+        + Sub<Output = T>
+        + SubAssign
+        + Debug,
+{
+    fn apply(&self, vector: &Vector<T, N>) -> Vector<T, N> {
+        self.product(vector).into_iter().collect()
+    }
+
+    fn diagonal(&self) -> Option<Vector<T, N>> {
+        Some(
+            (0..N)
+                .map(|idx| self.get(idx, idx).copied().unwrap_or_default())
+                .collect(),
+        )
+    }
+}
+
+impl<T, F, const N: usize> LinearOperator<T, N> for F
+where
+    F: Fn(&Vector<T, N>) -> Vector<T, N>,
+{
+    fn apply(&self, vector: &Vector<T, N>) -> Vector<T, N> {
+        self(vector)
+    }
+}
+
+/// `M⁻¹` of the chosen preconditioner, kept as the inverse diagonal for Jacobi
+struct InverseDiagonal<T, const N: usize>(Option<Vector<T, N>>);
+
+impl<T, const N: usize> InverseDiagonal<T, N>
+where
+    T: Default
+        + Copy
+        + FromPrimitive
+        + ToPrimitive
+        + Mul<Output = T>
+        + MulAssign
+        + Add<Output = T>
+        + AddAssign
+        + Sub<Output = T>
+        + SubAssign
+        + Debug
+        + Float,
+{
+    fn new(
+        operator: &impl LinearOperator<T, N>,
+        preconditioner: Preconditioner,
+    ) -> Result<Self, Error> {
+        match preconditioner {
+            Preconditioner::None => Ok(InverseDiagonal(None)),
+            Preconditioner::Jacobi => {
+                let mut diagonal = operator.diagonal().ok_or(Error::InvalidPreconditioner)?;
+
+                for num in diagonal.iter_mut() {
+                    if *num == T::zero() {
+                        return Err(Error::InvalidPreconditioner);
+                    }
+                    *num = num.recip();
+                }
+
+                Ok(InverseDiagonal(Some(diagonal)))
+            }
+        }
+    }
+
+    /// `M⁻¹r`
+    fn apply(&self, residual: &Vector<T, N>) -> Vector<T, N> {
+        match &self.0 {
+            Some(inverse) => residual.entrywise(inverse),
+            None => residual.clone(),
+        }
+    }
+}
+
+/// `y += αx`
+fn axpy<T, const N: usize>(y: &mut Vector<T, N>, alpha: T, x: &Vector<T, N>)
+where
+    T: Copy + Mul<Output = T> + AddAssign,
+{
+    for (num, x_num) in y.iter_mut().zip(x.iter()) {
+        *num += alpha * *x_num;
+    }
+}
+
+/// Builds the report from the true residual `‖b - Ax‖ / ‖b‖`, which the residual updated by
+/// the iteration itself can drift away from. `converged` is decided from the true residual too
+fn finish<T, const N: usize>(
+    operator: &impl LinearOperator<T, N>,
+    b: &Vector<T, N>,
+    b_norm: T,
+    solution: Vector<T, N>,
+    iterations: usize,
+    tolerance: T,
+) -> SolveReport<T, N>
+where
+    T: Default
+        + Copy
+        + FromPrimitive
+        + ToPrimitive
+        + Mul<Output = T>
+        + MulAssign
+        + Add<Output = T>
+        + AddAssign
+        + Sub<Output = T>
+        + SubAssign
+        + Debug
+        + Float,
+{
+    let residual = b.sub_vector(&operator.apply(&solution)).magnitude() / b_norm;
+
+    SolveReport {
+        solution,
+        iterations,
+        residual,
+        converged: residual <= tolerance,
+    }
+}
+
+/// Vector of zeros, placed on the stack or heap like `Vector::new_random`
+fn zeros<T, const N: usize>() -> Vector<T, N>
+where
+    T: Default + Float,
+{
+    (0..N).map(|_| T::zero()).collect()
+}
+
+/// Report for `b = 0`, where `x = 0` is the exact solution
+fn zero_rhs_report<T, const N: usize>() -> SolveReport<T, N>
+where
+    T: Default + Float,
+{
+    SolveReport {
+        solution: zeros(),
+        iterations: 0,
+        residual: T::zero(),
+        converged: true,
+    }
+}
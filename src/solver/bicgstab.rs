@@ -0,0 +1,176 @@
+use std::{
+    fmt::Debug,
+    ops::{Add, AddAssign, Mul, MulAssign, Sub, SubAssign},
+};
+
+use num::{Float, FromPrimitive, ToPrimitive};
+
+use crate::{error::Error, math_vector::MathVector, vector::Vector};
+
+use super::{
+    axpy, finish, zero_rhs_report, zeros, InverseDiagonal, LinearOperator, SolveReport,
+    SolverOptions,
+};
+
+/// Solves `Ax = b` with the right preconditioned biconjugate gradient stabilized method.
+///
+/// Works for general non-symmetric `A` at two applications of `A` per iteration. The method can
+/// break down before converging, in which case the report says it didn't converge and holds the
+/// last iterate.
+///
+/// Returns an error if the Jacobi preconditioner is requested for an operator without a usable
+/// diagonal.
+/// ```rust
+/// # use sickmath::*;
+/// let matrix: Matrix<f64, 2, 2> = Matrix::new([[3.0, 2.0], [-1.0, 2.0]]);
+/// let b: Vector<f64, 2> = Vector::new([5.0, 1.0]);
+///
+/// let report = bicgstab(&matrix, &b, &SolverOptions::default()).unwrap();
+///
+/// assert!(report.converged);
+/// assert!((report.solution[0] - 1.0).abs() < 1e-10);
+/// assert!((report.solution[1] - 1.0).abs() < 1e-10);
+/// ```
+pub fn bicgstab<T, const N: usize>(
+    operator: &impl LinearOperator<T, N>,
+    b: &Vector<T, N>,
+    options: &SolverOptions<T>,
+) -> Result<SolveReport<T, N>, Error>
+where
+    T: Default
+        + Copy
+        + FromPrimitive
+        + ToPrimitive
+        + Mul<Output = T>
+        + MulAssign
+        + Add<Output = T>
+        + AddAssign
+        + Sub<Output = T>
+        + SubAssign
+        + Debug
+        + Float,
+{
+    let preconditioner = InverseDiagonal::new(operator, options.preconditioner)?;
+    let b_norm = b.magnitude();
+    if b_norm == T::zero() {
+        return Ok(zero_rhs_report());
+    }
+
+    let mut x: Vector<T, N> = zeros();
+    let mut r = b.clone();
+    let r_hat = r.clone();
+    let mut p: Vector<T, N> = zeros();
+    let mut v: Vector<T, N> = zeros();
+    let (mut rho, mut alpha, mut omega) = (T::one(), T::one(), T::one());
+    let mut iterations = 0;
+
+    while iterations < options.max_iterations {
+        let rho_next = r_hat.dot(&r);
+        if rho_next == T::zero() || omega == T::zero() {
+            break;
+        }
+
+        let beta = (rho_next / rho) * (alpha / omega);
+        rho = rho_next;
+
+        // p = r + β(p - ωv)
+        axpy(&mut p, -omega, &v);
+        p.scalar_mut(beta);
+        p.add_vector_mut(&r);
+
+        let y = preconditioner.apply(&p);
+        v = operator.apply(&y);
+
+        let r_hat_v = r_hat.dot(&v);
+        if r_hat_v == T::zero() {
+            break;
+        }
+        alpha = rho / r_hat_v;
+        iterations += 1;
+
+        let mut s = r.clone();
+        axpy(&mut s, -alpha, &v);
+        axpy(&mut x, alpha, &y);
+
+        if s.magnitude() <= options.tolerance * b_norm {
+            break;
+        }
+
+        let z = preconditioner.apply(&s);
+        let t = operator.apply(&z);
+        let t_t = t.dot(&t);
+        omega = if t_t == T::zero() {
+            T::zero()
+        } else {
+            t.dot(&s) / t_t
+        };
+
+        axpy(&mut x, omega, &z);
+        r = s;
+        axpy(&mut r, -omega, &t);
+
+        if r.magnitude() <= options.tolerance * b_norm {
+            break;
+        }
+    }
+
+    Ok(finish(
+        operator,
+        b,
+        b_norm,
+        x,
+        iterations,
+        options.tolerance,
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        bicgstab, CooMatrix, LinearOperator, Matrix, Preconditioner, SolverOptions, Vector,
+    };
+
+    #[test]
+    fn solves_non_symmetric_sparse_system() {
+        // Convection-diffusion like operator, diagonally dominant but not symmetric
+        let mut builder: CooMatrix<f64, 40, 40> = CooMatrix::new();
+        for idx in 0..40 {
+            builder.push(idx, idx, 4.0 + idx as f64 * 0.05).unwrap();
+            if idx > 0 {
+                builder.push(idx, idx - 1, -1.5).unwrap();
+            }
+            if idx < 39 {
+                builder.push(idx, idx + 1, -0.5).unwrap();
+            }
+        }
+        let operator = builder.to_csc();
+        let expected: Vector<f64, 40> = (0..40).map(|idx| 1.0 + (idx as f64).cos()).collect();
+        let b = operator.apply(&expected);
+
+        for preconditioner in [Preconditioner::None, Preconditioner::Jacobi] {
+            let options = SolverOptions {
+                preconditioner,
+                ..SolverOptions::default()
+            };
+            let report = bicgstab(&operator, &b, &options).unwrap();
+
+            assert!(report.converged);
+            assert!(report.residual <= 1e-9);
+            for idx in 0..40 {
+                assert!((report.solution[idx] - expected[idx]).abs() < 1e-8);
+            }
+        }
+    }
+
+    #[test]
+    fn zero_right_hand_side() {
+        let matrix: Matrix<f64, 2, 2> = Matrix::new([[1.0, 2.0], [3.0, 4.0]]);
+
+        let report =
+            bicgstab(&matrix, &Vector::new([0.0, 0.0]), &SolverOptions::default()).unwrap();
+
+        assert!(report.converged);
+        assert_eq!(report.iterations, 0);
+        assert_eq!(report.solution, Vector::new([0.0, 0.0]));
+    }
+}
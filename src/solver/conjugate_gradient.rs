@@ -0,0 +1,168 @@
+use std::{
+    fmt::Debug,
+    ops::{Add, AddAssign, Mul, MulAssign, Sub, SubAssign},
+};
+
+use num::{Float, FromPrimitive, ToPrimitive};
+
+use crate::{error::Error, math_vector::MathVector, vector::Vector};
+
+use super::{
+    axpy, finish, zero_rhs_report, zeros, InverseDiagonal, LinearOperator, SolveReport,
+    SolverOptions,
+};
+
+/// Solves `Ax = b` with the preconditioned conjugate gradient method.
+///
+/// `A` has to be symmetric positive definite. Every iteration costs one application of `A`,
+/// which makes it the cheapest of the solvers when it applies. If `A` turns out not to be
+/// positive definite the iteration stops early and reports that it didn't converge.
+///
+/// Returns an error if the Jacobi preconditioner is requested for an operator without a usable
+/// diagonal.
+/// ```rust
+/// # use sickmath::*;
+/// let matrix: Matrix<f64, 2, 2> = Matrix::new([[4.0, 1.0], [1.0, 3.0]]);
+/// let b: Vector<f64, 2> = Vector::new([1.0, 2.0]);
+///
+/// let report = conjugate_gradient(&matrix, &b, &SolverOptions::default()).unwrap();
+///
+/// assert!(report.converged);
+/// assert!((report.solution[0] - 1.0 / 11.0).abs() < 1e-10);
+/// assert!((report.solution[1] - 7.0 / 11.0).abs() < 1e-10);
+/// ```
+pub fn conjugate_gradient<T, const N: usize>(
+    operator: &impl LinearOperator<T, N>,
+    b: &Vector<T, N>,
+    options: &SolverOptions<T>,
+) -> Result<SolveReport<T, N>, Error>
+where
+    T: Default
+        + Copy
+        + FromPrimitive
+        + ToPrimitive
+        + Mul<Output = T>
+        + MulAssign
+        + Add<Output = T>
+        + AddAssign
+        + Sub<Output = T>
+        + SubAssign
+        + Debug
+        + Float,
+{
+    let preconditioner = InverseDiagonal::new(operator, options.preconditioner)?;
+    let b_norm = b.magnitude();
+    if b_norm == T::zero() {
+        return Ok(zero_rhs_report());
+    }
+
+    let mut x: Vector<T, N> = zeros();
+    let mut r = b.clone();
+    let mut z = preconditioner.apply(&r);
+    let mut p = z.clone();
+    let mut rz = r.dot(&z);
+    let mut iterations = 0;
+
+    while iterations < options.max_iterations {
+        let ap = operator.apply(&p);
+        let p_ap = p.dot(&ap);
+
+        // A direction of non-positive curvature means `A` isn't positive definite
+        if p_ap <= T::zero() || p_ap.is_nan() {
+            break;
+        }
+
+        let alpha = rz / p_ap;
+        axpy(&mut x, alpha, &p);
+        axpy(&mut r, -alpha, &ap);
+        iterations += 1;
+
+        if r.magnitude() <= options.tolerance * b_norm {
+            break;
+        }
+
+        z = preconditioner.apply(&r);
+        let rz_next = r.dot(&z);
+        let beta = rz_next / rz;
+        rz = rz_next;
+
+        p.scalar_mut(beta);
+        p.add_vector_mut(&z);
+    }
+
+    Ok(finish(
+        operator,
+        b,
+        b_norm,
+        x,
+        iterations,
+        options.tolerance,
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        conjugate_gradient, CooMatrix, Error, LinearOperator, Matrix, Preconditioner,
+        SolverOptions, Vector,
+    };
+
+    #[test]
+    fn solves_sparse_laplacian() {
+        // Tridiagonal 1D Laplacian, symmetric positive definite
+        let mut builder: CooMatrix<f64, 50, 50> = CooMatrix::new();
+        for idx in 0..50 {
+            builder.push(idx, idx, 2.0 + idx as f64 * 0.1).unwrap();
+            if idx > 0 {
+                builder.push(idx, idx - 1, -1.0).unwrap();
+                builder.push(idx - 1, idx, -1.0).unwrap();
+            }
+        }
+        let operator = builder.to_csr();
+        let expected: Vector<f64, 50> = (0..50).map(|idx| (idx as f64).sin()).collect();
+        let b = operator.apply(&expected);
+
+        for preconditioner in [Preconditioner::None, Preconditioner::Jacobi] {
+            let options = SolverOptions {
+                preconditioner,
+                ..SolverOptions::default()
+            };
+            let report = conjugate_gradient(&operator, &b, &options).unwrap();
+
+            assert!(report.converged);
+            assert!(report.residual <= 1e-10);
+            for idx in 0..50 {
+                assert!((report.solution[idx] - expected[idx]).abs() < 1e-8);
+            }
+        }
+    }
+
+    #[test]
+    fn reports_iteration_limit() {
+        let matrix: Matrix<f64, 3, 3> =
+            Matrix::new([[4.0, 1.0, 0.0], [1.0, 3.0, 1.0], [0.0, 1.0, 2.0]]);
+        let options = SolverOptions {
+            max_iterations: 1,
+            ..SolverOptions::default()
+        };
+
+        let report = conjugate_gradient(&matrix, &Vector::new([1.0, 2.0, 3.0]), &options).unwrap();
+
+        assert!(!report.converged);
+        assert_eq!(report.iterations, 1);
+    }
+
+    #[test]
+    fn jacobi_needs_diagonal() {
+        let identity = |x: &Vector<f64, 2>| x.clone();
+        let options = SolverOptions {
+            preconditioner: Preconditioner::Jacobi,
+            ..SolverOptions::default()
+        };
+
+        assert_eq!(
+            conjugate_gradient(&identity, &Vector::new([1.0, 2.0]), &options),
+            Err(Error::InvalidPreconditioner)
+        );
+    }
+}
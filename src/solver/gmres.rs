@@ -0,0 +1,235 @@
+use std::{
+    fmt::Debug,
+    ops::{Add, AddAssign, Mul, MulAssign, Sub, SubAssign},
+};
+
+use num::{Float, FromPrimitive, ToPrimitive};
+
+use crate::{error::Error, math_vector::MathVector, vector::Vector};
+
+use super::{
+    axpy, finish, zero_rhs_report, zeros, InverseDiagonal, LinearOperator, SolveReport,
+    SolverOptions,
+};
+
+/// Solves `Ax = b` with the restarted, right preconditioned generalized minimal residual method.
+///
+/// Works for any non-singular `A` and never breaks down, at the cost of storing a basis of
+/// `options.restart` vectors. Every iteration costs one application of `A`.
+///
+/// Returns an error if the Jacobi preconditioner is requested for an operator without a usable
+/// diagonal, or if `A` maps the residual to zero, which only happens for a singular `A`. When `A`
+/// is singular on a later Krylov basis vector, GMRES stops with the best solution in the basis
+/// so far.
+/// ```rust
+/// # use sickmath::*;
+/// let matrix: Matrix<f64, 3, 3> = Matrix::new([[2.0, 1.0, 0.0], [0.0, 3.0, 1.0], [1.0, 0.0, 4.0]]);
+/// let b: Vector<f64, 3> = Vector::new([3.0, 4.0, 5.0]);
+///
+/// let report = gmres(&matrix, &b, &SolverOptions::default()).unwrap();
+///
+/// assert!(report.converged);
+/// assert!(report.solution.iter().all(|num| (num - 1.0).abs() < 1e-10));
+/// ```
+pub fn gmres<T, const N: usize>(
+    operator: &impl LinearOperator<T, N>,
+    b: &Vector<T, N>,
+    options: &SolverOptions<T>,
+) -> Result<SolveReport<T, N>, Error>
+where
+    T: Default
+        + Copy
+        + FromPrimitive
+        + ToPrimitive
+        + Mul<Output = T>
+        + MulAssign
+        + Add<Output = T>
+        + AddAssign
+        + Sub<Output = T>
+        + SubAssign
+        + Debug
+        + Float,
+{
+    let preconditioner = InverseDiagonal::new(operator, options.preconditioner)?;
+    let b_norm = b.magnitude();
+    if b_norm == T::zero() {
+        return Ok(zero_rhs_report());
+    }
+
+    let restart = options.restart.max(1);
+    let mut x: Vector<T, N> = zeros();
+    let mut iterations = 0;
+    let mut breakdown = false;
+
+    // Every restart checks the true residual, so the estimate from the rotations below is only
+    // used to end a cycle early
+    while iterations < options.max_iterations && !breakdown {
+        let r = b.sub_vector(&operator.apply(&x));
+        let beta = r.magnitude();
+        if beta <= options.tolerance * b_norm {
+            break;
+        }
+
+        // Arnoldi basis, Hessenberg matrix stored by column, and the Givens rotations that
+        // reduce it to upper triangular form as it grows
+        let mut basis = vec![r.scalar(beta.recip())];
+        let mut hessenberg: Vec<Vec<T>> = Vec::with_capacity(restart);
+        let mut rotations: Vec<(T, T)> = Vec::with_capacity(restart);
+        let mut g = vec![beta];
+
+        while hessenberg.len() < restart && iterations < options.max_iterations {
+            let j = hessenberg.len();
+            let mut w = operator.apply(&preconditioner.apply(&basis[j]));
+            let mut column = Vec::with_capacity(j + 2);
+            iterations += 1;
+
+            // Modified Gram-Schmidt
+            for v in basis.iter() {
+                let h = w.dot(v);
+                axpy(&mut w, -h, v);
+                column.push(h);
+            }
+            let w_norm = w.magnitude();
+            column.push(w_norm);
+            let column_norm = column
+                .iter()
+                .fold(T::zero(), |acc, num| acc + *num * *num)
+                .sqrt();
+
+            for (i, (cos, sin)) in rotations.iter().enumerate() {
+                let (upper, lower) = (column[i], column[i + 1]);
+                column[i] = *cos * upper + *sin * lower;
+                column[i + 1] = -*sin * upper + *cos * lower;
+            }
+
+            // The rotations keep the norm of the column, so a pivot that vanishes next to it
+            // means `A` maps the new basis vector into the span of the previous ones. The
+            // operator is singular on the Krylov space, and the columns so far are all that can
+            // be solved for
+            let (upper, lower) = (column[j], column[j + 1]);
+            let radius = upper.hypot(lower);
+            if radius <= T::epsilon() * column_norm {
+                breakdown = true;
+                break;
+            }
+
+            let (cos, sin) = (upper / radius, lower / radius);
+            column[j] = radius;
+            column[j + 1] = T::zero();
+            rotations.push((cos, sin));
+
+            g.push(-sin * g[j]);
+            g[j] = cos * g[j];
+
+            hessenberg.push(column);
+
+            if g[j + 1].abs() <= options.tolerance * b_norm {
+                break;
+            }
+            // A zero norm means the Krylov space is invariant and the solution is exact
+            if w_norm == T::zero() {
+                break;
+            }
+
+            basis.push(w.scalar(w_norm.recip()));
+        }
+
+        // Without a single usable column `A` maps the residual itself to zero
+        if hessenberg.is_empty() {
+            return Err(Error::SingularMatrix);
+        }
+
+        // Back substitution on the triangular system, whose pivots are all non-zero, then
+        // x += M⁻¹Vy
+        let size = hessenberg.len();
+        let mut y = vec![T::zero(); size];
+        for row in (0..size).rev() {
+            let mut acc = g[row];
+            for col in row + 1..size {
+                acc -= hessenberg[col][row] * y[col];
+            }
+            y[row] = acc / hessenberg[row][row];
+        }
+
+        let mut update: Vector<T, N> = zeros();
+        for (v, coefficient) in basis.iter().zip(y.iter()) {
+            axpy(&mut update, *coefficient, v);
+        }
+        x.add_vector_mut(&preconditioner.apply(&update));
+    }
+
+    Ok(finish(
+        operator,
+        b,
+        b_norm,
+        x,
+        iterations,
+        options.tolerance,
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        gmres, CooMatrix, Error, LinearOperator, Matrix, Preconditioner, SolverOptions, Vector,
+    };
+
+    #[test]
+    fn restarted_gmres_solves_sparse_system() {
+        let mut builder: CooMatrix<f64, 60, 60> = CooMatrix::new();
+        for idx in 0..60 {
+            builder.push(idx, idx, 3.0 + (idx % 7) as f64).unwrap();
+            if idx > 0 {
+                builder.push(idx, idx - 1, -1.0).unwrap();
+            }
+            if idx + 3 < 60 {
+                builder.push(idx, idx + 3, 0.7).unwrap();
+            }
+        }
+        let operator = builder.to_csr();
+        let expected: Vector<f64, 60> = (0..60).map(|idx| (idx as f64 * 0.3).sin()).collect();
+        let b = operator.apply(&expected);
+
+        for preconditioner in [Preconditioner::None, Preconditioner::Jacobi] {
+            // A short restart length forces several restarts
+            let options = SolverOptions {
+                preconditioner,
+                restart: 5,
+                ..SolverOptions::default()
+            };
+            let report = gmres(&operator, &b, &options).unwrap();
+
+            assert!(report.converged);
+            assert!(report.iterations > 5);
+            assert!(report.residual <= 1e-9);
+            for idx in 0..60 {
+                assert!((report.solution[idx] - expected[idx]).abs() < 1e-8);
+            }
+        }
+    }
+
+    #[test]
+    fn singular_operator() {
+        // The second column is zero, so `A` has no inverse
+        let matrix: Matrix<f64, 2, 2> = Matrix::new([[1.0, 0.0], [1.0, 0.0]]);
+
+        assert_eq!(
+            gmres(&matrix, &Vector::new([0.0, 1.0]), &SolverOptions::default()),
+            Err(Error::SingularMatrix)
+        );
+
+        // `b` is in the range of `A` but only reached through a basis vector `A` maps to zero
+        let matrix: Matrix<f64, 3, 3> =
+            Matrix::new([[0.0, 0.0, 0.0], [1.0, 0.0, 0.0], [0.0, 1.0, 0.0]]);
+        let report = gmres(
+            &matrix,
+            &Vector::new([1.0, 0.0, 0.0]),
+            &SolverOptions::default(),
+        )
+        .unwrap();
+
+        assert!(report.solution.iter().all(|num| num.is_finite()));
+        assert!(!report.converged);
+        assert!(report.residual <= 1.0);
+    }
+}
@@ -0,0 +1,140 @@
+pub mod coo;
+pub mod csc;
+pub mod csr;
+
+pub use coo::CooMatrix;
+pub use csc::CscMatrix;
+pub use csr::CsrMatrix;
+
+use std::ops::AddAssign;
+
+use crate::{dvector::DVector, error::Error};
+
+/// Checks that `vector` has the `N` entries a sparse matrix with `N` columns multiplies
+fn check_len<T, const N: usize>(vector: &DVector<T>) -> Result<(), Error> {
+    if vector.len() != N {
+        return Err(Error::DimensionMismatch {
+            expected: N,
+            found: vector.len(),
+        });
+    }
+
+    Ok(())
+}
+
+/// Compressed storage shared by CSR and CSC.
+///
+/// The non-zeros of major line `i` (a row for CSR, a column for CSC) are
+/// `values[offsets[i]..offsets[i + 1]]`, with their minor index at the same position in `indices`.
+/// Minor indices are sorted and unique within every major line.
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) struct Compressed<T> {
+    offsets: Vec<usize>,
+    indices: Vec<usize>,
+    values: Vec<T>,
+}
+
+impl<T> Compressed<T> {
+    /// Compresses triplets into `major_len` lines, summing duplicate entries
+    fn from_triplets(major: &[usize], minor: &[usize], values: &[T], major_len: usize) -> Self
+    where
+        T: Copy + AddAssign,
+    {
+        let mut order: Vec<usize> = (0..values.len()).collect();
+        order.sort_by_key(|&idx| (major[idx], minor[idx]));
+
+        let mut offsets = vec![0; major_len + 1];
+        let mut indices: Vec<usize> = Vec::with_capacity(values.len());
+        let mut compressed_values: Vec<T> = Vec::with_capacity(values.len());
+        let mut previous: Option<(usize, usize)> = None;
+
+        for idx in order {
+            let entry = (major[idx], minor[idx]);
+
+            if previous == Some(entry) {
+                if let Some(last) = compressed_values.last_mut() {
+                    *last += values[idx];
+                }
+            } else {
+                offsets[entry.0 + 1] += 1;
+                indices.push(entry.1);
+                compressed_values.push(values[idx]);
+                previous = Some(entry);
+            }
+        }
+
+        for line in 0..major_len {
+            offsets[line + 1] += offsets[line];
+        }
+
+        Compressed {
+            offsets,
+            indices,
+            values: compressed_values,
+        }
+    }
+
+    /// Number of stored entries
+    fn nnz(&self) -> usize {
+        self.values.len()
+    }
+
+    /// Minor indices and values of the non-zeros in major line `line`
+    fn line(&self, line: usize) -> impl Iterator<Item = (usize, &T)> {
+        let range = self.offsets[line]..self.offsets[line + 1];
+
+        self.indices[range.clone()]
+            .iter()
+            .copied()
+            .zip(self.values[range].iter())
+    }
+
+    /// `(major, minor, value)` of every non-zero, ordered by major then minor index
+    fn iter(&self) -> impl Iterator<Item = (usize, usize, &T)> {
+        (0..self.offsets.len() - 1)
+            .flat_map(move |line| self.line(line).map(move |(idx, num)| (line, idx, num)))
+    }
+
+    /// The entry at `(major, minor)` if it's stored
+    fn get(&self, major: usize, minor: usize) -> Option<&T> {
+        let range = self.offsets[major]..self.offsets[major + 1];
+
+        self.indices[range.clone()]
+            .binary_search(&minor)
+            .ok()
+            .map(|idx| &self.values[range.start + idx])
+    }
+
+    /// Swaps the roles of major and minor lines, which turns CSR into CSC of the same matrix and
+    /// the other way around
+    fn transposed(&self, minor_len: usize) -> Self
+    where
+        T: Copy,
+    {
+        let mut offsets = vec![0; minor_len + 1];
+
+        for idx in self.indices.iter() {
+            offsets[idx + 1] += 1;
+        }
+        for line in 0..minor_len {
+            offsets[line + 1] += offsets[line];
+        }
+
+        // Walking the major lines in order keeps the new minor indices sorted
+        let mut next = offsets.clone();
+        let mut indices = vec![0; self.nnz()];
+        let mut values: Vec<Option<T>> = vec![None; self.nnz()];
+
+        for (major, minor, num) in self.iter() {
+            indices[next[minor]] = major;
+            values[next[minor]] = Some(*num);
+            next[minor] += 1;
+        }
+
+        Compressed {
+            offsets,
+            indices,
+            values: values.into_iter().flatten().collect(),
+        }
+    }
+}
@@ -0,0 +1,157 @@
+use std::ops::AddAssign;
+
+use crate::error::Error;
+
+use super::{Compressed, CscMatrix, CsrMatrix};
+
+/// Builds a sparse `M` x `N` matrix from `(row, col, value)` triplets.
+///
+/// Triplets can be pushed in any order. Duplicate entries are summed when converting into
+/// `CsrMatrix` or `CscMatrix`, which are the formats used for arithmetic.
+/// ```rust
+/// # use sickmath::*;
+/// let mut builder: CooMatrix<f64, 2, 3> = CooMatrix::new();
+/// builder.push(0, 2, 1.5).unwrap();
+/// builder.push(1, 0, -2.0).unwrap();
+/// builder.push(0, 2, 0.5).unwrap();
+///
+/// let csr = builder.to_csr();
+/// assert_eq!(csr.nnz(), 2);
+/// assert_eq!(csr.get(0, 2), Some(&2.0));
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct CooMatrix<T, const M: usize, const N: usize> {
+    rows: Vec<usize>,
+    cols: Vec<usize>,
+    values: Vec<T>,
+}
+
+impl<T, const M: usize, const N: usize> CooMatrix<T, M, N> {
+    pub fn new() -> Self {
+        CooMatrix {
+            rows: Vec::new(),
+            cols: Vec::new(),
+            values: Vec::new(),
+        }
+    }
+
+    /// Empty builder with room for `capacity` triplets
+    pub fn with_capacity(capacity: usize) -> Self {
+        CooMatrix {
+            rows: Vec::with_capacity(capacity),
+            cols: Vec::with_capacity(capacity),
+            values: Vec::with_capacity(capacity),
+        }
+    }
+
+    /// Adds a triplet. Returns an error if `(row, col)` lies outside of the matrix
+    pub fn push(&mut self, row: usize, col: usize, value: T) -> Result<(), Error> {
+        if row >= M || col >= N {
+            return Err(Error::IndexOutOfBounds {
+                index: (row, col),
+                shape: (M, N),
+            });
+        }
+
+        self.rows.push(row);
+        self.cols.push(col);
+        self.values.push(value);
+
+        Ok(())
+    }
+
+    /// Collects triplets taken from a matrix of the same shape, which are in bounds already
+    pub(super) fn from_valid_triplets(triplets: impl Iterator<Item = (usize, usize, T)>) -> Self {
+        let mut coo = CooMatrix::new();
+
+        for (row, col, value) in triplets {
+            debug_assert!(row < M && col < N);
+            coo.rows.push(row);
+            coo.cols.push(col);
+            coo.values.push(value);
+        }
+
+        coo
+    }
+
+    /// Number of pushed triplets, including duplicates
+    pub fn len(&self) -> usize {
+        self.values.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.values.is_empty()
+    }
+
+    /// Iterates over the triplets in the order they were pushed
+    pub fn iter(&self) -> impl Iterator<Item = (usize, usize, &T)> {
+        self.rows
+            .iter()
+            .zip(self.cols.iter())
+            .zip(self.values.iter())
+            .map(|((row, col), num)| (*row, *col, num))
+    }
+
+    pub fn to_csr(&self) -> CsrMatrix<T, M, N>
+    where
+        T: Copy + AddAssign,
+    {
+        CsrMatrix {
+            inner: Compressed::from_triplets(&self.rows, &self.cols, &self.values, M),
+        }
+    }
+
+    pub fn to_csc(&self) -> CscMatrix<T, M, N>
+    where
+        T: Copy + AddAssign,
+    {
+        CscMatrix {
+            inner: Compressed::from_triplets(&self.cols, &self.rows, &self.values, N),
+        }
+    }
+}
+
+impl<T, const M: usize, const N: usize> Default for CooMatrix<T, M, N> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn push_out_of_bounds() {
+        let mut builder: CooMatrix<u8, 2, 2> = CooMatrix::new();
+
+        assert_eq!(
+            builder.push(2, 0, 1),
+            Err(Error::IndexOutOfBounds {
+                index: (2, 0),
+                shape: (2, 2)
+            })
+        );
+        assert!(builder.is_empty());
+    }
+
+    #[test]
+    fn duplicates_are_summed() {
+        let mut builder: CooMatrix<i32, 3, 3> = CooMatrix::new();
+        for (row, col, value) in [(2, 1, 4), (0, 0, 1), (2, 1, -1), (1, 2, 5)] {
+            builder.push(row, col, value).unwrap();
+        }
+
+        let csr = builder.to_csr();
+        let csc = builder.to_csc();
+
+        assert_eq!(
+            csr.iter().collect::<Vec<_>>(),
+            vec![(0, 0, &1), (1, 2, &5), (2, 1, &3)]
+        );
+        assert_eq!(
+            csc.iter().collect::<Vec<_>>(),
+            vec![(0, 0, &1), (2, 1, &3), (1, 2, &5)]
+        );
+    }
+}
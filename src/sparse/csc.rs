@@ -0,0 +1,174 @@
+use std::{
+    fmt::Debug,
+    ops::{Add, AddAssign, Index, Mul, MulAssign, Sub, SubAssign},
+};
+
+use num::{FromPrimitive, ToPrimitive};
+
+use crate::{dvector::DVector, error::Error, matrix::Matrix};
+
+use super::{check_len, Compressed, CooMatrix, CsrMatrix};
+
+/// Compressed sparse column matrix.
+///
+/// Stores only the non-zeros of every column, which makes column access cheap. Build one from a
+/// `CooMatrix` or from a dense `Matrix`.
+/// ```rust
+/// # use sickmath::*;
+/// let dense: Matrix<i32, 2, 3> = Matrix::new([[0, 2, 0], [1, 0, 3]]);
+/// let sparse = CscMatrix::from_dense(&dense);
+///
+/// assert_eq!(sparse.column(2).collect::<Vec<_>>(), vec![(1, &3)]);
+/// assert_eq!(sparse.mul_vector(&DVector::new(vec![1, 1, 1])), Ok(DVector::new(vec![2, 4])));
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct CscMatrix<T, const M: usize, const N: usize> {
+    pub(crate) inner: Compressed<T>,
+}
+
+impl<T, const M: usize, const N: usize> CscMatrix<T, M, N> {
+    /// Number of stored non-zeros
+    pub fn nnz(&self) -> usize {
+        self.inner.nnz()
+    }
+
+    /// The entry at `(row, col)`, or `None` if it isn't stored
+    pub fn get(&self, row: usize, col: usize) -> Option<&T> {
+        self.inner.get(col, row)
+    }
+
+    /// Row index and value of every non-zero in `col`
+    pub fn column(&self, col: usize) -> impl Iterator<Item = (usize, &T)> {
+        self.inner.line(col)
+    }
+
+    /// `(row, col, value)` of every non-zero, ordered by column
+    pub fn iter(&self) -> impl Iterator<Item = (usize, usize, &T)> {
+        self.inner.iter().map(|(col, row, num)| (row, col, num))
+    }
+
+    pub fn to_csr(&self) -> CsrMatrix<T, M, N>
+    where
+        T: Copy,
+    {
+        CsrMatrix {
+            inner: self.inner.transposed(M),
+        }
+    }
+
+    pub fn to_coo(&self) -> CooMatrix<T, M, N>
+    where
+        T: Copy,
+    {
+        CooMatrix::from_valid_triplets(self.iter().map(|(row, col, num)| (row, col, *num)))
+    }
+
+    pub fn transpose(&self) -> CscMatrix<T, N, M>
+    where
+        T: Copy,
+    {
+        CscMatrix {
+            inner: self.inner.transposed(M),
+        }
+    }
+}
+
+impl<T, const M: usize, const N: usize> CscMatrix<T, M, N>
+where
+    T: Default
+        + Copy
+        + FromPrimitive
+        + ToPrimitive
+        + Mul<Output = T>
+        + MulAssign
+        + Add<Output = T>
+        + AddAssign
+        + Sub<Output = T>
+        + SubAssign
+        + Debug,
+{
+    /// Stores every entry of `matrix` that isn't `T::default()`
+    pub fn from_dense(matrix: &Matrix<T, M, N>) -> Self
+    where
+        T: PartialEq,
+    {
+        CsrMatrix::from_dense(matrix).to_csc()
+    }
+
+    pub fn to_dense(&self) -> Matrix<T, M, N> {
+        let mut dense: Matrix<T, M, N> = Matrix::default();
+
+        for (row, col, num) in self.iter() {
+            dense[row][col] = *num;
+        }
+
+        dense
+    }
+
+    /// Sparse matrix-vector product `Ax`. Returns an error if the length of `vector` isn't `N`
+    ///
+    /// Takes and returns a `DVector` since sparse matrices are often too large for a `Vector` on
+    /// the stack.
+    pub fn mul_vector(&self, vector: &DVector<T>) -> Result<DVector<T>, Error> {
+        check_len::<T, N>(vector)?;
+
+        Ok(DVector::new(self.product(vector)))
+    }
+
+    /// `Ax` for any `x` of length `N`
+    pub(crate) fn product(&self, vector: &impl Index<usize, Output = T>) -> Vec<T> {
+        let mut product = vec![T::default(); M];
+
+        for col in 0..N {
+            for (row, num) in self.column(col) {
+                product[row] += *num * vector[col];
+            }
+        }
+
+        product
+    }
+
+    /// Sparse-dense matrix product `AB`
+    pub fn mul_matrix<const P: usize>(&self, matrix: &Matrix<T, N, P>) -> Matrix<T, M, P> {
+        let mut product: Matrix<T, M, P> = Matrix::default();
+
+        for (row, col, num) in self.iter() {
+            for (acc, rhs_num) in product[row].iter_mut().zip(matrix[col].iter()) {
+                *acc += *num * *rhs_num;
+            }
+        }
+
+        product
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{CscMatrix, DVector, Error, Matrix, Vector};
+
+    #[test]
+    fn sparse_products_match_dense() {
+        let dense: Matrix<f64, 3, 2> = Matrix::new([[0.0, 1.5], [2.0, 0.0], [0.0, -1.0]]);
+        let rhs: Matrix<f64, 2, 3> = Matrix::new([[1.0, 2.0, 3.0], [4.0, 5.0, 6.0]]);
+        let vector: Vector<f64, 2> = Vector::new([2.0, 4.0]);
+
+        let sparse = CscMatrix::from_dense(&dense);
+
+        assert_eq!(sparse.mul_matrix(&rhs), dense.mult(&rhs));
+        assert_eq!(
+            sparse.mul_vector(&DVector::from(vector.clone())),
+            Ok(DVector::from(dense.mul_vector(&vector)))
+        );
+        assert_eq!(
+            sparse.mul_vector(&DVector::new(vec![1.0])),
+            Err(Error::DimensionMismatch {
+                expected: 2,
+                found: 1
+            })
+        );
+        assert_eq!(sparse.transpose().to_dense(), dense.transpose());
+        assert_eq!(sparse.to_csr().to_csc(), sparse);
+        assert_eq!(sparse.get(1, 0), Some(&2.0));
+        assert_eq!(sparse.get(0, 0), None);
+    }
+}
@@ -0,0 +1,176 @@
+use std::{
+    fmt::Debug,
+    ops::{Add, AddAssign, Index, Mul, MulAssign, Sub, SubAssign},
+};
+
+use num::{FromPrimitive, ToPrimitive};
+
+use crate::{dvector::DVector, error::Error, matrix::Matrix};
+
+use super::{check_len, Compressed, CooMatrix, CscMatrix};
+
+/// Compressed sparse row matrix.
+///
+/// Stores only the non-zeros of every row, which makes row access and products with a dense
+/// right hand side cheap. Build one from a `CooMatrix` or from a dense `Matrix`.
+/// ```rust
+/// # use sickmath::*;
+/// let dense: Matrix<i32, 2, 3> = Matrix::new([[0, 2, 0], [1, 0, 3]]);
+/// let sparse = CsrMatrix::from_dense(&dense);
+///
+/// assert_eq!(sparse.nnz(), 3);
+/// assert_eq!(sparse.mul_vector(&DVector::new(vec![1, 1, 1])), Ok(DVector::new(vec![2, 4])));
+/// assert_eq!(sparse.to_dense(), dense);
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct CsrMatrix<T, const M: usize, const N: usize> {
+    pub(crate) inner: Compressed<T>,
+}
+
+impl<T, const M: usize, const N: usize> CsrMatrix<T, M, N> {
+    /// Number of stored non-zeros
+    pub fn nnz(&self) -> usize {
+        self.inner.nnz()
+    }
+
+    /// The entry at `(row, col)`, or `None` if it isn't stored
+    pub fn get(&self, row: usize, col: usize) -> Option<&T> {
+        self.inner.get(row, col)
+    }
+
+    /// Column index and value of every non-zero in `row`
+    pub fn row(&self, row: usize) -> impl Iterator<Item = (usize, &T)> {
+        self.inner.line(row)
+    }
+
+    /// `(row, col, value)` of every non-zero, ordered by row
+    pub fn iter(&self) -> impl Iterator<Item = (usize, usize, &T)> {
+        self.inner.iter()
+    }
+
+    pub fn to_csc(&self) -> CscMatrix<T, M, N>
+    where
+        T: Copy,
+    {
+        CscMatrix {
+            inner: self.inner.transposed(N),
+        }
+    }
+
+    pub fn to_coo(&self) -> CooMatrix<T, M, N>
+    where
+        T: Copy,
+    {
+        CooMatrix::from_valid_triplets(self.iter().map(|(row, col, num)| (row, col, *num)))
+    }
+
+    pub fn transpose(&self) -> CsrMatrix<T, N, M>
+    where
+        T: Copy,
+    {
+        CsrMatrix {
+            inner: self.inner.transposed(N),
+        }
+    }
+}
+
+impl<T, const M: usize, const N: usize> CsrMatrix<T, M, N>
+where
+    T: Default
+        + Copy
+        + FromPrimitive
+        + ToPrimitive
+        + Mul<Output = T>
+        + MulAssign
+        + Add<Output = T>
+        + AddAssign
+        + Sub<Output = T>
+        + SubAssign
+        + Debug,
+{
+    /// Stores every entry of `matrix` that isn't `T::default()`
+    pub fn from_dense(matrix: &Matrix<T, M, N>) -> Self
+    where
+        T: PartialEq,
+    {
+        let triplets = matrix.iter().enumerate().flat_map(|(idx_row, row)| {
+            row.iter()
+                .enumerate()
+                .filter(|(_, num)| **num != T::default())
+                .map(move |(idx_col, num)| (idx_row, idx_col, *num))
+        });
+
+        CooMatrix::from_valid_triplets(triplets).to_csr()
+    }
+
+    pub fn to_dense(&self) -> Matrix<T, M, N> {
+        let mut dense: Matrix<T, M, N> = Matrix::default();
+
+        for (row, col, num) in self.iter() {
+            dense[row][col] = *num;
+        }
+
+        dense
+    }
+
+    /// Sparse matrix-vector product `Ax`. Returns an error if the length of `vector` isn't `N`
+    ///
+    /// Takes and returns a `DVector` since sparse matrices are often too large for a `Vector` on
+    /// the stack.
+    pub fn mul_vector(&self, vector: &DVector<T>) -> Result<DVector<T>, Error> {
+        check_len::<T, N>(vector)?;
+
+        Ok(DVector::new(self.product(vector)))
+    }
+
+    /// `Ax` for any `x` of length `N`
+    pub(crate) fn product(&self, vector: &impl Index<usize, Output = T>) -> Vec<T> {
+        (0..M)
+            .map(|row| {
+                let mut acc = T::default();
+
+                for (col, num) in self.row(row) {
+                    acc += *num * vector[col];
+                }
+
+                acc
+            })
+            .collect()
+    }
+
+    /// Sparse-dense matrix product `AB`
+    pub fn mul_matrix<const P: usize>(&self, matrix: &Matrix<T, N, P>) -> Matrix<T, M, P> {
+        let mut product: Matrix<T, M, P> = Matrix::default();
+
+        for (row, col, num) in self.iter() {
+            for (acc, rhs_num) in product[row].iter_mut().zip(matrix[col].iter()) {
+                *acc += *num * *rhs_num;
+            }
+        }
+
+        product
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{CsrMatrix, DVector, Matrix, Vector};
+
+    #[test]
+    fn sparse_products_match_dense() {
+        let dense: Matrix<i32, 3, 4> = Matrix::new([[0, 2, 0, 0], [1, 0, 0, -3], [0, 0, 0, 0]]);
+        let rhs: Matrix<i32, 4, 2> = Matrix::new([[1, 2], [3, 4], [5, 6], [7, 8]]);
+        let vector: Vector<i32, 4> = Vector::new([1, -1, 2, 0]);
+
+        let sparse = CsrMatrix::from_dense(&dense);
+
+        assert_eq!(sparse.mul_matrix(&rhs), dense.mult(&rhs));
+        assert_eq!(
+            sparse.mul_vector(&DVector::from(vector.clone())),
+            Ok(DVector::from(dense.mul_vector(&vector)))
+        );
+        assert_eq!(sparse.transpose().to_dense(), dense.transpose());
+        assert_eq!(sparse.row(1).collect::<Vec<_>>(), vec![(0, &1), (3, &-3)]);
+        assert_eq!(sparse.row(2).count(), 0);
+    }
+}
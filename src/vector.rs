@@ -1,11 +1,15 @@
-use std::ops::{Index, IndexMut};
+use std::{
+    convert::TryFrom,
+    ops::{Index, IndexMut},
+};
 
-use rand::{distributions::Standard, prelude::Distribution};
+use rand::{distributions::Standard, prelude::Distribution, Rng};
 
 use crate::vector::{
     large_vector::{into_vec::IntoVec, LargeVector},
     small_vector::{into_array::IntoArray, SmallVector},
 };
+use crate::{error::Error, random::seeded_rng};
 
 pub mod iterator;
 pub mod large_vector;
@@ -13,6 +17,10 @@ pub mod math;
 pub mod math_ops;
 pub mod small_vector;
 
+/// Length from which a `Vector` is a `LargeVector` when the variant is picked for the caller, as
+/// in `new_random`, `collect` and `TryFrom<Vec<T>>`
+pub(crate) const LARGE_LEN: usize = 5001;
+
 /// A mathematical vector that can either be allocated on the heap or stack.
 ///
 /// `Vector` has a fixed length determined by a const generic parameter. You can use any type tha's
@@ -87,6 +95,16 @@ where
         Self::Small(SmallVector::new(data))
     }
 
+    /// Same as `new` but returns an error instead of panicking when the length of `data` isn't `N`
+    /// ```rust
+    /// # use sickmath::*;
+    /// let vector: Result<Vector<u8, 3>, Error> = Vector::try_new(vec![1, 2]);
+    /// assert!(vector.is_err());
+    /// ```
+    pub fn try_new(data: impl IntoArray<T, N>) -> Result<Self, Error> {
+        Ok(Self::Small(SmallVector::try_new(data)?))
+    }
+
     /// Create a new random `SmallVectr`
     /// ```rust
     /// # use sickmath::*;
@@ -97,13 +115,70 @@ where
         T: Copy,
         Standard: Distribution<T>,
     {
-        if N < 5001 {
-            Self::Small(SmallVector::new_random())
+        Self::new_random_with(&mut rand::thread_rng())
+    }
+
+    /// Same as `new_random` but draws the items in order from `rng`.
+    ///
+    /// Small and large vectors consume `rng` the same way, so the items only depend on the state
+    /// of `rng`.
+    /// ```rust
+    /// # use sickmath::*;
+    /// use rand::SeedableRng;
+    ///
+    /// let mut rng = rand::rngs::StdRng::seed_from_u64(7);
+    /// let vector: Vector<f64, 3> = Vector::new_random_with(&mut rng);
+    ///
+    /// let mut rng = rand::rngs::StdRng::seed_from_u64(7);
+    /// assert_eq!(vector, Vector::new_large_random_with(&mut rng));
+    /// ```
+    pub fn new_random_with(rng: &mut impl Rng) -> Self
+    where
+        T: Copy,
+        Standard: Distribution<T>,
+    {
+        if N < LARGE_LEN {
+            Self::Small(SmallVector::new_random_with(rng))
         } else {
-            Self::Large(LargeVector::new_random())
+            Self::Large(LargeVector::new_random_with(rng))
         }
     }
 
+    /// Vector with every item drawn from `distribution`, such as `Normal`, `Bernoulli` or
+    /// `rand::distributions::Uniform`
+    /// ```rust
+    /// # use sickmath::*;
+    /// use rand::distributions::Uniform;
+    ///
+    /// let mut rng = rand::thread_rng();
+    /// let vector: Vector<f64, 8> = Vector::from_distribution(&Uniform::new(-1.0, 1.0), &mut rng);
+    ///
+    /// assert!(vector.iter().all(|num| (-1.0..1.0).contains(num)));
+    /// ```
+    pub fn from_distribution(distribution: &impl Distribution<T>, rng: &mut impl Rng) -> Self
+    where
+        T: Copy,
+    {
+        (0..N).map(|_| distribution.sample(rng)).collect()
+    }
+
+    /// Random vector that is the same for every run with the same `seed`.
+    ///
+    /// The items come from a ChaCha8 generator, whose stream doesn't change between releases or
+    /// platforms.
+    /// ```rust
+    /// # use sickmath::*;
+    /// let vector: Vector<f32, 4> = Vector::from_seed(42);
+    /// assert_eq!(vector, Vector::from_seed(42));
+    /// ```
+    pub fn from_seed(seed: u64) -> Self
+    where
+        T: Copy,
+        Standard: Distribution<T>,
+    {
+        Self::new_random_with(&mut seeded_rng(seed))
+    }
+
     /// Create a new `LargeVector` from any data type that implements `IntoVec`
     /// ```rust
     /// # use sickmath::*;
@@ -113,6 +188,16 @@ where
         Self::Large(LargeVector::new(data))
     }
 
+    /// Same as `new_large` but returns an error when the length of `data` isn't `N`
+    /// ```rust
+    /// # use sickmath::*;
+    /// let large_vector: Result<Vector<u16, 3>, Error> = Vector::try_new_large(vec![1, 2, 3]);
+    /// assert!(large_vector.is_ok());
+    /// ```
+    pub fn try_new_large(data: impl IntoVec<T, N>) -> Result<Self, Error> {
+        Ok(Self::Large(LargeVector::try_new(data)?))
+    }
+
     /// Create a new random `LargeVector`
     /// ```rust
     /// # use sickmath::*;
@@ -124,6 +209,24 @@ where
     {
         Self::Large(LargeVector::new_random())
     }
+
+    /// Same as `new_large_random` but draws the items in order from `rng`
+    pub fn new_large_random_with(rng: &mut impl Rng) -> Self
+    where
+        Standard: Distribution<T>,
+    {
+        Self::Large(LargeVector::new_random_with(rng))
+    }
+}
+
+impl<T, const N: usize> Vector<T, N> {
+    /// The items of either variant as one slice
+    pub(crate) fn items(&self) -> &[T] {
+        match self {
+            Self::Small(small_vector) => &small_vector.data,
+            Self::Large(large_vector) => &large_vector.data,
+        }
+    }
 }
 
 impl<T, const N: usize> Default for Vector<T, N>
@@ -135,6 +238,27 @@ where
     }
 }
 
+/// Creates a `SmallVector` or a `LargeVector` depending on `N`, failing if the length of the `Vec`
+/// isn't `N`
+/// ```rust
+/// # use sickmath::*;
+/// use std::convert::TryFrom;
+///
+/// let vector = Vector::<f64, 3>::try_from(vec![0.2, 0.5, 0.8]);
+/// assert!(vector.is_ok());
+/// ```
+impl<T, const N: usize> TryFrom<Vec<T>> for Vector<T, N> {
+    type Error = Error;
+
+    fn try_from(data: Vec<T>) -> Result<Self, Self::Error> {
+        if N < LARGE_LEN {
+            Ok(Self::Small(SmallVector::try_new(data)?))
+        } else {
+            Ok(Self::Large(LargeVector::try_new(data)?))
+        }
+    }
+}
+
 impl<T, const N: usize> Index<usize> for Vector<T, N> {
     type Output = T;
 
@@ -1,17 +1,9 @@
 use crate::vector::{large_vector::LargeVector, small_vector::SmallVector};
 
-use super::Vector;
+use super::{Vector, LARGE_LEN};
 
 use std::iter::FromIterator;
 impl<'a, T, const N: usize> Vector<T, N> {
-    pub fn into_iter(self) -> IntoIter<T, N> {
-        IntoIter {
-            data: self,
-            current: 0,
-            end: N,
-        }
-    }
-
     pub fn iter(&'a self) -> Iter<'a, T, N> {
         Iter {
             data: self,
@@ -44,7 +36,7 @@ where
     #[inline]
     fn next(&mut self) -> Option<Self::Item> {
         if self.current == self.end {
-            return None;
+            None
         } else {
             let current = self.current;
             self.current += 1;
@@ -104,7 +96,7 @@ where
     #[inline]
     fn next(&mut self) -> Option<Self::Item> {
         if self.current == self.end {
-            return None;
+            None
         } else {
             let current = self.current;
             self.current += 1;
@@ -148,7 +140,7 @@ where
     #[inline]
     fn next(&mut self) -> Option<Self::Item> {
         if self.current == self.end {
-            return None;
+            None
         } else {
             let current = self.current;
             self.current += 1;
@@ -156,11 +148,11 @@ where
             match self.data {
                 Vector::Small(small_vector) => {
                     let ptr = small_vector.data.as_mut_ptr();
-                    return Some(unsafe { &mut *ptr.add(current) });
+                    Some(unsafe { &mut *ptr.add(current) })
                 }
                 Vector::Large(large_vector) => {
                     let ptr = large_vector.data.as_mut_ptr();
-                    return Some(unsafe { &mut *ptr.add(current) });
+                    Some(unsafe { &mut *ptr.add(current) })
                 }
             }
         }
@@ -172,15 +164,11 @@ where
     T: Default + Copy,
 {
     fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Vector<T, N> {
-        let limit = 5001;
-
-        if N < limit {
+        if N < LARGE_LEN {
             let mut collector: [T; N] = [T::default(); N];
 
-            let mut idx = 0;
-            for item in iter {
+            for (idx, item) in iter.into_iter().enumerate() {
                 collector[idx] = item;
-                idx += 1;
             }
 
             Vector::Small(SmallVector::new(collector))
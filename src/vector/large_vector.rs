@@ -4,6 +4,7 @@ pub mod math;
 pub mod math_ops;
 
 use self::into_vec::IntoVec;
+use crate::error::Error;
 
 use rand::{distributions::Standard, prelude::Distribution, Rng};
 use std::convert::TryInto;
@@ -22,11 +23,24 @@ impl<T, const N: usize> LargeVector<T, N> {
         }
     }
 
+    pub fn try_new(data: impl IntoVec<T, N>) -> Result<Self, Error> {
+        Ok(LargeVector {
+            data: data.try_into_vec()?,
+        })
+    }
+
     pub fn new_random() -> Self
     where
         Standard: Distribution<T>,
     {
-        let mut rng = rand::thread_rng();
+        Self::new_random_with(&mut rand::thread_rng())
+    }
+
+    /// Draws the items in order from `rng`
+    pub fn new_random_with(rng: &mut impl Rng) -> Self
+    where
+        Standard: Distribution<T>,
+    {
         let mut random_data: Vec<T> = Vec::with_capacity(N);
 
         for _ in 0..N {
@@ -64,13 +78,26 @@ impl<T, const N: usize> LargeVector<T, N>
 where
     T: Clone,
 {
+    // Consumes `self` to hand out the buffer without copying it. The name predates the lint,
+    // which wants `into_vec`, and renaming it would break callers
+    #[allow(clippy::wrong_self_convention)]
     pub fn to_vec(self) -> Vec<T> {
         self.data
     }
 
+    // Consumes `self` for the same reason as `to_vec`
+    #[allow(clippy::wrong_self_convention)]
     pub fn to_array(self) -> [T; N] {
-        self.data.try_into().unwrap_or_else(|v: Vec<T>| {
-            panic!("Expected a Vec of length {} but it was {}", N, v.len())
-        })
+        self.try_to_array()
+            .unwrap_or_else(|err: Error| panic!("{}", err))
+    }
+
+    pub fn try_to_array(self) -> Result<[T; N], Error> {
+        self.data
+            .try_into()
+            .map_err(|v: Vec<T>| Error::LengthMismatch {
+                expected: N,
+                found: v.len(),
+            })
     }
 }
@@ -1,13 +1,27 @@
-use crate::vector::small_vector::SmallVector;
+use crate::{error::Error, vector::small_vector::SmallVector};
 
 pub trait IntoVec<T, const N: usize> {
     fn into_vec(self) -> Vec<T>;
+
+    /// Same as `into_vec` but returns an error if the length isn't `N`
+    fn try_into_vec(self) -> Result<Vec<T>, Error>;
 }
 
 impl<T, const N: usize> IntoVec<T, N> for Vec<T> {
     fn into_vec(self) -> Vec<T> {
         self
     }
+
+    fn try_into_vec(self) -> Result<Vec<T>, Error> {
+        if self.len() != N {
+            return Err(Error::LengthMismatch {
+                expected: N,
+                found: self.len(),
+            });
+        }
+
+        Ok(self)
+    }
 }
 
 impl<T, const N: usize> IntoVec<T, N> for [T; N]
@@ -22,6 +36,10 @@ where
 
         converted_vec
     }
+
+    fn try_into_vec(self) -> Result<Vec<T>, Error> {
+        Ok(IntoVec::<T, N>::into_vec(self))
+    }
 }
 
 impl<T, const N: usize> IntoVec<T, N> for SmallVector<T, N>
@@ -31,6 +49,10 @@ where
     fn into_vec(self) -> Vec<T> {
         self.to_vec()
     }
+
+    fn try_into_vec(self) -> Result<Vec<T>, Error> {
+        Ok(self.to_vec())
+    }
 }
 
 #[cfg(test)]
@@ -44,6 +66,19 @@ mod tests {
         assert_eq!(IntoVec::<u8, 4>::into_vec(vec), vec![1, 2, 3, 4]);
     }
 
+    #[test]
+    fn try_into_vec_from_vec() {
+        let vec = vec![1, 2, 3, 4, 5];
+
+        assert_eq!(
+            IntoVec::<u8, 4>::try_into_vec(vec),
+            Err(Error::LengthMismatch {
+                expected: 4,
+                found: 5
+            })
+        );
+    }
+
     #[test]
     fn into_vec_from_array() {
         let array = [1, 2, 3, 4];
@@ -1,14 +1,6 @@
 use super::LargeVector;
 use std::iter::FromIterator;
 impl<'a, T, const N: usize> LargeVector<T, N> {
-    pub fn into_iter(self) -> IntoIter<T, N> {
-        IntoIter {
-            data: self,
-            current: 0,
-            end: N,
-        }
-    }
-
     pub fn iter(&'a self) -> Iter<'a, T, N> {
         Iter {
             data: self,
@@ -41,12 +33,12 @@ where
     #[inline]
     fn next(&mut self) -> Option<Self::Item> {
         if self.current == self.end {
-            return None;
+            None
         } else {
             let current = self.current;
             self.current += 1;
 
-            return Some(self.data.data[current]);
+            Some(self.data.data[current])
         }
     }
 }
@@ -98,12 +90,12 @@ where
     #[inline]
     fn next(&mut self) -> Option<Self::Item> {
         if self.current == self.end {
-            return None;
+            None
         } else {
             let current = self.current;
             self.current += 1;
 
-            return Some(&self.data.data[current]);
+            Some(&self.data.data[current])
         }
     }
 }
@@ -139,13 +131,13 @@ where
     #[inline]
     fn next(&mut self) -> Option<Self::Item> {
         if self.current == self.end {
-            return None;
+            None
         } else {
             let current = self.current;
             self.current += 1;
 
             let ptr = self.data.data.as_mut_ptr();
-            return Some(unsafe { &mut *ptr.add(current) });
+            Some(unsafe { &mut *ptr.add(current) })
         }
     }
 }
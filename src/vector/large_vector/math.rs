@@ -1,12 +1,11 @@
-use num::integer::Roots;
 use std::{
     fmt::Debug,
     ops::{Add, AddAssign, Mul, MulAssign, Sub, SubAssign},
 };
 
-use num::{FromPrimitive, ToPrimitive};
+use num::{CheckedAdd, CheckedMul, Float, FromPrimitive, ToPrimitive};
 
-use crate::{math_vector::MathVector, matrix::Matrix};
+use crate::{error::Error, math_vector::MathVector, matrix::Matrix, simd};
 
 use super::LargeVector;
 
@@ -24,79 +23,116 @@ where
         + SubAssign
         + Debug,
 {
-    fn scalar(&self, scalar: isize) -> Self {
-        let mut scaled_vec: Vec<T> = Vec::with_capacity(N);
-
-        for num in self.data.iter() {
-            scaled_vec.push(*num * FromPrimitive::from_isize(scalar).expect("Expected isize"));
-        }
+    fn scalar(&self, scalar: T) -> Self {
+        let mut scaled = LargeVector {
+            data: self.data.clone(),
+        };
+        scaled.scalar_mut(scalar);
 
-        LargeVector { data: scaled_vec }
+        scaled
     }
 
-    fn scalar_mut(&mut self, scalar: isize) {
+    fn scalar_mut(&mut self, scalar: T) {
+        if simd::scale(&mut self.data, scalar) {
+            return;
+        }
+
         for num in self.data.iter_mut() {
-            *num *= FromPrimitive::from_isize(scalar).expect("Expected isize")
+            *num *= scalar;
         }
     }
 
-    fn dot(&self, rhs: &(impl MathVector<T, N> + std::ops::Index<usize, Output = T>)) -> isize {
+    fn dot(&self, rhs: &(impl MathVector<T, N> + std::ops::Index<usize, Output = T>)) -> T {
+        if let Some(product) = rhs.as_slice().and_then(|rhs| simd::dot(&self.data, rhs)) {
+            return product;
+        }
+
         let mut acc: T = T::default();
 
         for idx in 0..N {
             acc += self.data[idx] * rhs[idx];
         }
 
-        ToPrimitive::to_isize(&acc).expect("Type of T is not supported")
+        acc
     }
 
-    fn add_vector(&self, rhs: &(impl MathVector<T, N> + std::ops::Index<usize, Output = T>)) -> Self {
-        let mut added_vec: Vec<T> = Vec::with_capacity(N);
+    fn try_dot(
+        &self,
+        rhs: &(impl MathVector<T, N> + std::ops::Index<usize, Output = T>),
+    ) -> Result<T, Error>
+    where
+        T: CheckedAdd + CheckedMul,
+    {
+        let mut acc: T = T::default();
 
         for idx in 0..N {
-            added_vec.push(self.data[idx] + rhs[idx]);
+            let product = self.data[idx]
+                .checked_mul(&rhs[idx])
+                .ok_or(Error::Overflow)?;
+            acc = acc.checked_add(&product).ok_or(Error::Overflow)?;
         }
 
-        LargeVector { data: added_vec }
+        Ok(acc)
+    }
+
+    fn add_vector(&self, rhs: &(impl MathVector<T, N> + std::ops::Index<usize, Output = T>)) -> Self {
+        let mut added = LargeVector {
+            data: self.data.clone(),
+        };
+        added.add_vector_mut(rhs);
+
+        added
     }
 
     fn add_vector_mut(&mut self, rhs: &(impl MathVector<T, N> + std::ops::Index<usize, Output = T>)) {
+        if let Some(rhs) = rhs.as_slice() {
+            if simd::add_assign(&mut self.data, rhs) {
+                return;
+            }
+        }
+
         for (idx, num) in self.data.iter_mut().enumerate() {
             *num += rhs[idx];
         }
     }
 
     fn sub_vector(&self, rhs: &(impl MathVector<T, N> + std::ops::Index<usize, Output = T>)) -> Self {
-        let mut subtracted_vec: Vec<T> = Vec::with_capacity(N);
-
-        for idx in 0..N {
-            subtracted_vec.push(self.data[idx] - rhs[idx]);
-        }
+        let mut subtracted = LargeVector {
+            data: self.data.clone(),
+        };
+        subtracted.sub_vector_mut(rhs);
 
-        LargeVector {
-            data: subtracted_vec,
-        }
+        subtracted
     }
 
     fn sub_vector_mut(&mut self, rhs: &(impl MathVector<T, N> + std::ops::Index<usize, Output = T>)) {
+        if let Some(rhs) = rhs.as_slice() {
+            if simd::sub_assign(&mut self.data, rhs) {
+                return;
+            }
+        }
+
         for (idx, num) in self.data.iter_mut().enumerate() {
             *num -= rhs[idx];
         }
     }
 
     fn entrywise(&self, rhs: &(impl MathVector<T, N> + std::ops::Index<usize, Output = T>)) -> Self {
-        let mut multiplied_vec: Vec<T> = Vec::with_capacity(N);
-
-        for idx in 0..N {
-            multiplied_vec.push(self.data[idx] * rhs[idx]);
-        }
+        let mut multiplied = LargeVector {
+            data: self.data.clone(),
+        };
+        multiplied.entrywise_mut(rhs);
 
-        LargeVector {
-            data: multiplied_vec,
-        }
+        multiplied
     }
 
     fn entrywise_mut(&mut self, rhs: &(impl MathVector<T, N> + std::ops::Index<usize, Output = T>)) {
+        if let Some(rhs) = rhs.as_slice() {
+            if simd::mul_assign(&mut self.data, rhs) {
+                return;
+            }
+        }
+
         for (idx, num) in self.data.iter_mut().enumerate() {
             *num *= rhs[idx];
         }
@@ -127,6 +163,35 @@ where
         self.data[2] = data[0] * rhs[1] - data[1] * rhs[0];
     }
 
+    fn try_cross(
+        &self,
+        rhs: &(impl MathVector<T, N> + std::ops::Index<usize, Output = T>),
+    ) -> Result<Self, Error> {
+        if N != 3 {
+            return Err(Error::DimensionMismatch {
+                expected: 3,
+                found: N,
+            });
+        }
+
+        Ok(self.cross(rhs))
+    }
+
+    fn try_cross_mut(
+        &mut self,
+        rhs: &(impl MathVector<T, N> + std::ops::Index<usize, Output = T>),
+    ) -> Result<(), Error> {
+        if N != 3 {
+            return Err(Error::DimensionMismatch {
+                expected: 3,
+                found: N,
+            });
+        }
+
+        self.cross_mut(rhs);
+        Ok(())
+    }
+
     fn tensor_prod<const M: usize>(
         &self,
         rhs: &(impl MathVector<T, N> + std::ops::Index<usize, Output = T>),
@@ -142,27 +207,31 @@ where
         tensor_product
     }
 
-    fn magnitude(&self) -> usize {
+    fn magnitude(&self) -> T
+    where
+        T: Float,
+    {
         let mut acc: T = T::default();
 
         for num in self.iter() {
             acc += *num * *num;
         }
 
-        let isize_acc = ToPrimitive::to_usize(&acc)
-            .expect("Valid integers are required to calculate the magnitude");
-
-        isize_acc.sqrt()
+        acc.sqrt()
     }
 
-    fn sum(&self) -> isize {
+    fn sum(&self) -> T {
         let mut acc: T = T::default();
 
         for num in self.iter() {
             acc += *num;
         }
 
-        ToPrimitive::to_isize(&acc).expect("Valid integers are required to calculate the sum")
+        acc
+    }
+
+    fn as_slice(&self) -> Option<&[T]> {
+        Some(&self.data)
     }
 }
 
@@ -358,6 +427,34 @@ mod tests {
         assert_eq!(large_vector, crossed_large_vector);
     }
 
+    #[test]
+    fn large_vector_try_cross_mut() {
+        let mut large_vector: LargeVector<i8, 3> = LargeVector {
+            data: vec![1, 2, 3],
+        };
+
+        let large_vector_2: LargeVector<i8, 3> = LargeVector {
+            data: vec![4, 5, 6],
+        };
+
+        assert_eq!(large_vector.try_cross_mut(&large_vector_2), Ok(()));
+        assert_eq!(large_vector.data, vec![-3, 6, -3]);
+    }
+
+    #[test]
+    fn large_vector_try_dot_overflow() {
+        let large_vector_1: LargeVector<i8, 2> = LargeVector {
+            data: vec![100, 100],
+        };
+
+        let large_vector_2: LargeVector<i8, 2> = LargeVector { data: vec![1, 1] };
+
+        assert_eq!(
+            large_vector_1.try_dot(&large_vector_2),
+            Err(Error::Overflow)
+        );
+    }
+
     #[test]
     fn large_vector_tensor_prod() {
         let large_vector_1: LargeVector<u8, 3> = LargeVector {
@@ -377,10 +474,26 @@ mod tests {
 
     #[test]
     fn large_vector_magnitude() {
-        let large_vector: LargeVector<i8, 2> = LargeVector { data: vec![2, 2] };
+        let large_vector: LargeVector<f64, 2> = LargeVector {
+            data: vec![3.0, 4.0],
+        };
 
-        assert_eq!(large_vector.magnitude(), 2);
+        assert_eq!(large_vector.magnitude(), 5.0);
     }
+
+    #[test]
+    fn large_vector_float_dot() {
+        let large_vector_1: LargeVector<f64, 3> = LargeVector {
+            data: vec![0.5, 1.5, 2.0],
+        };
+
+        let large_vector_2: LargeVector<f64, 3> = LargeVector {
+            data: vec![0.5, 0.5, 0.25],
+        };
+
+        assert_eq!(large_vector_1.dot(&large_vector_2), 1.5);
+    }
+
     #[test]
     fn large_vector_sum() {
         let large_vector: LargeVector<i8, 3> = LargeVector {
@@ -3,9 +3,9 @@ use std::{
     ops::{Add, AddAssign, Index, Mul, MulAssign, Sub, SubAssign},
 };
 
-use num::{FromPrimitive, ToPrimitive};
+use num::{CheckedAdd, CheckedMul, Float, FromPrimitive, ToPrimitive};
 
-use crate::{math_vector::MathVector, matrix::Matrix};
+use crate::{error::Error, math_vector::MathVector, matrix::Matrix};
 
 use super::Vector;
 
@@ -23,27 +23,40 @@ where
         + SubAssign
         + Debug,
 {
-    fn scalar(&self, scalar: isize) -> Self {
+    fn scalar(&self, scalar: T) -> Self {
         match self {
             Self::Small(small_vector) => Vector::Small(small_vector.scalar(scalar)),
             Self::Large(large_vector) => Vector::Large(large_vector.scalar(scalar)),
         }
     }
 
-    fn scalar_mut(&mut self, scalar: isize) {
+    fn scalar_mut(&mut self, scalar: T) {
         match self {
             Self::Small(small_vector) => small_vector.scalar_mut(scalar),
             Self::Large(large_vector) => large_vector.scalar_mut(scalar),
         };
     }
 
-    fn dot(&self, rhs: &(impl MathVector<T, N> + std::ops::Index<usize, Output = T>)) -> isize {
+    fn dot(&self, rhs: &(impl MathVector<T, N> + std::ops::Index<usize, Output = T>)) -> T {
         match self {
             Self::Small(small_vector) => small_vector.dot(rhs),
             Self::Large(large_vector) => large_vector.dot(rhs),
         }
     }
 
+    fn try_dot(
+        &self,
+        rhs: &(impl MathVector<T, N> + std::ops::Index<usize, Output = T>),
+    ) -> Result<T, Error>
+    where
+        T: CheckedAdd + CheckedMul,
+    {
+        match self {
+            Self::Small(small_vector) => small_vector.try_dot(rhs),
+            Self::Large(large_vector) => large_vector.try_dot(rhs),
+        }
+    }
+
     fn add_vector(&self, rhs: &(impl MathVector<T, N> + std::ops::Index<usize, Output = T>)) -> Self {
         match self {
             Self::Small(small_vector) => Vector::Small(small_vector.add_vector(rhs)),
@@ -100,6 +113,26 @@ where
         }
     }
 
+    fn try_cross(
+        &self,
+        rhs: &(impl MathVector<T, N> + Index<usize, Output = T>),
+    ) -> Result<Self, Error> {
+        match self {
+            Self::Small(small_vector) => Ok(Vector::Small(small_vector.try_cross(rhs)?)),
+            Self::Large(large_vector) => Ok(Vector::Large(large_vector.try_cross(rhs)?)),
+        }
+    }
+
+    fn try_cross_mut(
+        &mut self,
+        rhs: &(impl MathVector<T, N> + Index<usize, Output = T>),
+    ) -> Result<(), Error> {
+        match self {
+            Self::Small(small_vector) => small_vector.try_cross_mut(rhs),
+            Self::Large(large_vector) => large_vector.try_cross_mut(rhs),
+        }
+    }
+
     fn tensor_prod<const M: usize>(
         &self,
         rhs: &(impl MathVector<T, N> + Index<usize, Output = T>),
@@ -110,17 +143,62 @@ where
         }
     }
 
-    fn magnitude(&self) -> usize {
+    fn magnitude(&self) -> T
+    where
+        T: Float,
+    {
         match self {
             Self::Small(small_vector) => small_vector.magnitude(),
             Self::Large(large_vector) => large_vector.magnitude(),
         }
     }
 
-    fn sum(&self) -> isize {
+    fn sum(&self) -> T {
         match self {
             Self::Small(small_vector) => small_vector.sum(),
             Self::Large(large_vector) => large_vector.sum(),
         }
     }
+
+    fn as_slice(&self) -> Option<&[T]> {
+        match self {
+            Self::Small(small_vector) => small_vector.as_slice(),
+            Self::Large(large_vector) => large_vector.as_slice(),
+        }
+    }
+}
+
+impl<T, const M: usize> Vector<T, M>
+where
+    T: Default
+        + Copy
+        + FromPrimitive
+        + ToPrimitive
+        + Mul<Output = T>
+        + MulAssign
+        + Add<Output = T>
+        + AddAssign
+        + Sub<Output = T>
+        + SubAssign
+        + Debug,
+{
+    /// Vector-matrix product `xᵀA`, treating the vector as a row vector
+    /// ```rust
+    /// # use sickmath::*;
+    /// let vector: Vector<i32, 2> = Vector::new([1, -1]);
+    /// let matrix: Matrix<i32, 2, 3> = Matrix::new([[1, 2, 3], [4, 5, 6]]);
+    ///
+    /// assert_eq!(vector.mul_matrix(&matrix), Vector::new([-3, -3, -3]));
+    /// ```
+    pub fn mul_matrix<const N: usize>(&self, matrix: &Matrix<T, M, N>) -> Vector<T, N> {
+        let mut product: Vector<T, N> = (0..N).map(|_| T::default()).collect();
+
+        for (row, num) in matrix.iter().zip(self.iter()) {
+            for (col, acc) in product.iter_mut().enumerate() {
+                *acc += *num * row[col];
+            }
+        }
+
+        product
+    }
 }
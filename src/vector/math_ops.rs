@@ -73,7 +73,7 @@ where
     fn sub(self, rhs: Self) -> Self {
         match self {
             Self::Small(small_vector) => Vector::Small(small_vector.sub_vector(&rhs)),
-            Self::Large(large_vector) => Vector::Large(large_vector.add_vector(&rhs)),
+            Self::Large(large_vector) => Vector::Large(large_vector.sub_vector(&rhs)),
         }
     }
 }
@@ -95,7 +95,7 @@ where
     fn sub_assign(&mut self, rhs: Self) {
         match self {
             Self::Small(small_vector) => small_vector.sub_vector_mut(&rhs),
-            Self::Large(large_vector) => large_vector.add_vector_mut(&rhs),
+            Self::Large(large_vector) => large_vector.sub_vector_mut(&rhs),
         }
     }
 }
@@ -145,3 +145,22 @@ where
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn large_vector_sub() {
+        let vector_1: Vector<i8, 3> = Vector::new_large([5, 6, 7]);
+
+        let vector_2: Vector<i8, 3> = Vector::new([1, 2, 3]);
+
+        assert_eq!(vector_1.clone() - vector_2.clone(), Vector::new([4, 4, 4]));
+
+        let mut vector = vector_1;
+        vector -= vector_2;
+
+        assert_eq!(vector, Vector::new([4, 4, 4]));
+    }
+}
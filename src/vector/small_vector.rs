@@ -7,6 +7,7 @@ pub mod math_ops;
 use std::ops::{Deref, Index, IndexMut};
 
 use self::into_array::IntoArray;
+use crate::error::Error;
 use rand::{distributions::Standard, prelude::Distribution, Rng};
 
 #[derive(Debug, Clone, PartialEq)]
@@ -21,12 +22,26 @@ impl<T, const N: usize> SmallVector<T, N> {
         }
     }
 
+    pub fn try_new(data: impl IntoArray<T, N>) -> Result<Self, Error> {
+        Ok(SmallVector {
+            data: data.try_into_array()?,
+        })
+    }
+
     pub fn new_random() -> Self
     where
         T: Default + Copy,
         Standard: Distribution<T>,
     {
-        let mut rng = rand::thread_rng();
+        Self::new_random_with(&mut rand::thread_rng())
+    }
+
+    /// Draws the items in order from `rng`
+    pub fn new_random_with(rng: &mut impl Rng) -> Self
+    where
+        T: Default + Copy,
+        Standard: Distribution<T>,
+    {
         let mut random_data: [T; N] = [T::default(); N];
 
         for num in &mut random_data {
@@ -67,12 +82,17 @@ impl<T, const N: usize> SmallVector<T, N>
 where
     T: Clone,
 {
+    // Consumes `self` to keep the signature of `LargeVector::to_vec`, which the lint would have
+    // renamed to `into_vec`
+    #[allow(clippy::wrong_self_convention)]
     pub fn to_vec(self) -> Vec<T> {
         self.data.to_vec()
     }
 }
 
 impl<T, const N: usize> SmallVector<T, N> {
+    // Consumes `self` to keep the signature of `LargeVector::to_array`
+    #[allow(clippy::wrong_self_convention)]
     pub fn to_array(self) -> [T; N] {
         self.data
     }
@@ -1,9 +1,12 @@
 use std::convert::TryInto;
 
-use crate::vector::large_vector::LargeVector;
+use crate::{error::Error, vector::large_vector::LargeVector};
 
 pub trait IntoArray<T, const N: usize> {
     fn into_array(self) -> [T; N];
+
+    /// Same as `into_array` but returns an error instead of panicking on a length mismatch
+    fn try_into_array(self) -> Result<[T; N], Error>;
 }
 
 impl<T, const N: usize> IntoArray<T, N> for [T; N]
@@ -13,12 +16,22 @@ where
     fn into_array(self) -> [T; N] {
         self
     }
+
+    fn try_into_array(self) -> Result<[T; N], Error> {
+        Ok(self)
+    }
 }
 
 impl<T, const N: usize> IntoArray<T, N> for Vec<T> {
     fn into_array(self) -> [T; N] {
-        self.try_into().unwrap_or_else(|v: Vec<T>| {
-            panic!("Expected a Vec of length {} but it was {}", N, v.len())
+        self.try_into_array()
+            .unwrap_or_else(|err: Error| panic!("{}", err))
+    }
+
+    fn try_into_array(self) -> Result<[T; N], Error> {
+        self.try_into().map_err(|v: Vec<T>| Error::LengthMismatch {
+            expected: N,
+            found: v.len(),
         })
     }
 }
@@ -30,6 +43,10 @@ where
     fn into_array(self) -> [T; N] {
         self.to_array()
     }
+
+    fn try_into_array(self) -> Result<[T; N], Error> {
+        self.try_to_array()
+    }
 }
 
 #[cfg(test)]
@@ -52,6 +69,19 @@ mod tests {
         assert_eq!(array.into_vec(), [1, 2, 3, 4]);
     }
 
+    #[test]
+    fn try_into_array_from_vec() {
+        let vec = vec![1, 2, 3];
+
+        assert_eq!(
+            IntoArray::<u8, 4>::try_into_array(vec),
+            Err(Error::LengthMismatch {
+                expected: 4,
+                found: 3
+            })
+        );
+    }
+
     #[test]
     fn into_array_from_large_vector() {
         let large_vector = LargeVector {
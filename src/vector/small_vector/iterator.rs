@@ -2,14 +2,6 @@ use super::SmallVector;
 use std::iter::FromIterator;
 
 impl<'a, T, const N: usize> SmallVector<T, N> {
-    pub fn into_iter(self) -> IntoIter<T, N> {
-        IntoIter {
-            data: self,
-            current: 0,
-            end: N,
-        }
-    }
-
     pub fn iter(&'a self) -> Iter<'a, T, N> {
         Iter {
             data: self,
@@ -42,12 +34,12 @@ where
     #[inline]
     fn next(&mut self) -> Option<Self::Item> {
         if self.current == self.end {
-            return None;
+            None
         } else {
             let current = self.current;
             self.current += 1;
 
-            return Some(self.data.data[current]);
+            Some(self.data.data[current])
         }
     }
 }
@@ -99,12 +91,12 @@ where
     #[inline]
     fn next(&mut self) -> Option<Self::Item> {
         if self.current == self.end {
-            return None;
+            None
         } else {
             let current = self.current;
             self.current += 1;
 
-            return Some(&self.data.data[current]);
+            Some(&self.data.data[current])
         }
     }
 }
@@ -140,13 +132,13 @@ where
     #[inline]
     fn next(&mut self) -> Option<Self::Item> {
         if self.current == self.end {
-            return None;
+            None
         } else {
             let current = self.current;
             self.current += 1;
 
             let ptr = self.data.data.as_mut_ptr();
-            return Some(unsafe { &mut *ptr.add(current) });
+            Some(unsafe { &mut *ptr.add(current) })
         }
     }
 }
@@ -158,10 +150,8 @@ where
     fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> SmallVector<T, N> {
         let mut collector: [T; N] = [T::default(); N];
 
-        let mut idx = 0;
-        for item in iter {
+        for (idx, item) in iter.into_iter().enumerate() {
             collector[idx] = item;
-            idx += 1;
         }
 
         SmallVector { data: collector }
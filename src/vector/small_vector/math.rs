@@ -3,8 +3,8 @@ use std::{
     ops::{Add, AddAssign, Index, Mul, MulAssign, Sub, SubAssign},
 };
 
-use crate::{math_vector::MathVector, matrix::Matrix};
-use num::{integer::Roots, FromPrimitive, ToPrimitive};
+use crate::{error::Error, math_vector::MathVector, matrix::Matrix, simd};
+use num::{CheckedAdd, CheckedMul, Float, FromPrimitive, ToPrimitive};
 
 use super::SmallVector;
 
@@ -22,82 +22,106 @@ where
         + SubAssign
         + Debug,
 {
-    fn scalar(&self, scalar: isize) -> Self {
-        let mut scaled_array: [T; N] = [T::default(); N];
+    fn scalar(&self, scalar: T) -> Self {
+        let mut scaled = SmallVector { data: self.data };
+        scaled.scalar_mut(scalar);
 
-        let scalar: T = FromPrimitive::from_isize(scalar).expect("Expected isize");
-        for (idx, num) in self.data.iter().enumerate() {
-            scaled_array[idx] = *num * scalar;
-        }
-
-        SmallVector { data: scaled_array }
+        scaled
     }
 
-    fn scalar_mut(&mut self, scalar: isize) {
-        let scalar: T = FromPrimitive::from_isize(scalar).expect("Expected isize");
+    fn scalar_mut(&mut self, scalar: T) {
+        if simd::scale(&mut self.data, scalar) {
+            return;
+        }
+
         for num in self.data.iter_mut() {
-            *num = *num * scalar;
+            *num *= scalar;
         }
     }
 
-    fn dot(&self, rhs: &(impl MathVector<T, N> + Index<usize, Output = T>)) -> isize {
+    fn dot(&self, rhs: &(impl MathVector<T, N> + Index<usize, Output = T>)) -> T {
+        if let Some(product) = rhs.as_slice().and_then(|rhs| simd::dot(&self.data, rhs)) {
+            return product;
+        }
+
         let mut acc: T = T::default();
 
         for idx in 0..N {
             acc += self.data[idx] * rhs[idx];
         }
 
-        ToPrimitive::to_isize(&acc).expect("Type of T is not supported")
+        acc
     }
 
-    fn add_vector(&self, rhs: &(impl MathVector<T, N> + Index<usize, Output = T>)) -> Self {
-        let mut added_array: [T; N] = [T::default(); N];
+    fn try_dot(&self, rhs: &(impl MathVector<T, N> + Index<usize, Output = T>)) -> Result<T, Error>
+    where
+        T: CheckedAdd + CheckedMul,
+    {
+        let mut acc: T = T::default();
 
-        for (idx, num) in added_array.iter_mut().enumerate() {
-            *num = self.data[idx] + rhs[idx];
+        for idx in 0..N {
+            let product = self.data[idx]
+                .checked_mul(&rhs[idx])
+                .ok_or(Error::Overflow)?;
+            acc = acc.checked_add(&product).ok_or(Error::Overflow)?;
         }
 
-        SmallVector { data: added_array }
+        Ok(acc)
+    }
+
+    fn add_vector(&self, rhs: &(impl MathVector<T, N> + Index<usize, Output = T>)) -> Self {
+        let mut added = SmallVector { data: self.data };
+        added.add_vector_mut(rhs);
+
+        added
     }
 
     fn add_vector_mut(&mut self, rhs: &(impl MathVector<T, N> + Index<usize, Output = T>)) {
-        for (idx, num) in self.iter_mut().enumerate() {
+        if let Some(rhs) = rhs.as_slice() {
+            if simd::add_assign(&mut self.data, rhs) {
+                return;
+            }
+        }
+
+        for (idx, num) in self.data.iter_mut().enumerate() {
             *num += rhs[idx];
         }
     }
 
     fn sub_vector(&self, rhs: &(impl MathVector<T, N> + Index<usize, Output = T>)) -> Self {
-        let mut subtracted_array: [T; N] = [T::default(); N];
-
-        for (idx, num) in subtracted_array.iter_mut().enumerate() {
-            *num = self.data[idx] - rhs[idx];
-        }
+        let mut subtracted = SmallVector { data: self.data };
+        subtracted.sub_vector_mut(rhs);
 
-        SmallVector {
-            data: subtracted_array,
-        }
+        subtracted
     }
 
     fn sub_vector_mut(&mut self, rhs: &(impl MathVector<T, N> + Index<usize, Output = T>)) {
-        for (idx, num) in self.iter_mut().enumerate() {
+        if let Some(rhs) = rhs.as_slice() {
+            if simd::sub_assign(&mut self.data, rhs) {
+                return;
+            }
+        }
+
+        for (idx, num) in self.data.iter_mut().enumerate() {
             *num -= rhs[idx];
         }
     }
 
     fn entrywise(&self, rhs: &(impl MathVector<T, N> + Index<usize, Output = T>)) -> Self {
-        let mut multiplied_array: [T; N] = [T::default(); N];
-
-        for (idx, num) in multiplied_array.iter_mut().enumerate() {
-            *num = self.data[idx] * rhs[idx];
-        }
+        let mut multiplied = SmallVector { data: self.data };
+        multiplied.entrywise_mut(rhs);
 
-        SmallVector {
-            data: multiplied_array,
-        }
+        multiplied
     }
 
     fn entrywise_mut(&mut self, rhs: &(impl MathVector<T, N> + Index<usize, Output = T>)) {
-        for (idx, num) in self.iter_mut().enumerate() {
+        if let Some(rhs) = rhs.as_slice() {
+            if simd::mul_assign(&mut self.data, rhs) {
+                return;
+            }
+        }
+
+        for (idx, num) in self.data.iter_mut().enumerate() {
             *num *= rhs[idx];
         }
     }
@@ -123,12 +147,41 @@ where
             panic!("The cross product requires that the length of both vectors must be 3");
         }
 
-        let data = self.data.clone();
+        let data = self.data;
         self.data[0] = data[1] * rhs[2] - data[2] * rhs[1];
         self.data[1] = data[2] * rhs[0] - data[0] * rhs[2];
         self.data[2] = data[0] * rhs[1] - data[1] * rhs[0];
     }
 
+    fn try_cross(
+        &self,
+        rhs: &(impl MathVector<T, N> + Index<usize, Output = T>),
+    ) -> Result<Self, Error> {
+        if N != 3 {
+            return Err(Error::DimensionMismatch {
+                expected: 3,
+                found: N,
+            });
+        }
+
+        Ok(self.cross(rhs))
+    }
+
+    fn try_cross_mut(
+        &mut self,
+        rhs: &(impl MathVector<T, N> + Index<usize, Output = T>),
+    ) -> Result<(), Error> {
+        if N != 3 {
+            return Err(Error::DimensionMismatch {
+                expected: 3,
+                found: N,
+            });
+        }
+
+        self.cross_mut(rhs);
+        Ok(())
+    }
+
     fn tensor_prod<const M: usize>(
         &self,
         rhs: &(impl MathVector<T, N> + Index<usize, Output = T>),
@@ -144,27 +197,31 @@ where
         tensor_product
     }
 
-    fn magnitude(&self) -> usize {
+    fn magnitude(&self) -> T
+    where
+        T: Float,
+    {
         let mut acc: T = T::default();
 
         for num in self.iter() {
             acc += *num * *num;
         }
 
-        let isize_acc = ToPrimitive::to_usize(&acc)
-            .expect("Valid integers are required to calculate the magnitude");
-
-        isize_acc.sqrt()
+        acc.sqrt()
     }
 
-    fn sum(&self) -> isize {
+    fn sum(&self) -> T {
         let mut acc: T = T::default();
 
         for num in self.iter() {
             acc += *num;
         }
 
-        ToPrimitive::to_isize(&acc).expect("Valid integers are required to calculate the sum")
+        acc
+    }
+
+    fn as_slice(&self) -> Option<&[T]> {
+        Some(&self.data)
     }
 }
 
@@ -205,6 +262,27 @@ mod tests {
         assert_eq!(small_vector_1.dot(&small_vector_2), 70);
     }
 
+    #[test]
+    fn small_vector_try_dot() {
+        let small_vector_1: SmallVector<u8, 2> = SmallVector { data: [1, 2] };
+
+        let small_vector_2: SmallVector<u8, 2> = SmallVector { data: [3, 4] };
+
+        assert_eq!(small_vector_1.try_dot(&small_vector_2), Ok(11));
+    }
+
+    #[test]
+    fn small_vector_try_dot_overflow() {
+        let small_vector_1: SmallVector<u8, 2> = SmallVector { data: [100, 100] };
+
+        let small_vector_2: SmallVector<u8, 2> = SmallVector { data: [2, 1] };
+
+        assert_eq!(
+            small_vector_1.try_dot(&small_vector_2),
+            Err(Error::Overflow)
+        );
+    }
+
     #[test]
     fn small_vector_add_vector() {
         let small_vector_1: SmallVector<u8, 4> = SmallVector { data: [1, 2, 3, 4] };
@@ -314,6 +392,21 @@ mod tests {
         assert_eq!(small_vector, crossed_small_vector);
     }
 
+    #[test]
+    fn small_vector_try_cross() {
+        let small_vector_1: SmallVector<i8, 2> = SmallVector { data: [1, 2] };
+
+        let small_vector_2: SmallVector<i8, 2> = SmallVector { data: [4, 5] };
+
+        assert_eq!(
+            small_vector_1.try_cross(&small_vector_2),
+            Err(Error::DimensionMismatch {
+                expected: 3,
+                found: 2
+            })
+        );
+    }
+
     #[test]
     fn small_vector_tensor_prod() {
         let small_vector_1: SmallVector<u8, 3> = SmallVector { data: [1, 2, 3] };
@@ -329,10 +422,33 @@ mod tests {
 
     #[test]
     fn small_vector_magnitude() {
-        let small_vector: SmallVector<i8, 2> = SmallVector { data: [2, 2] };
+        let small_vector: SmallVector<f64, 2> = SmallVector { data: [3.0, 4.0] };
 
-        assert_eq!(small_vector.magnitude(), 2);
+        assert_eq!(small_vector.magnitude(), 5.0);
     }
+
+    #[test]
+    fn small_vector_float_dot() {
+        let small_vector_1: SmallVector<f64, 3> = SmallVector {
+            data: [0.5, 1.5, 2.0],
+        };
+
+        let small_vector_2: SmallVector<f64, 3> = SmallVector {
+            data: [0.5, 0.5, 0.25],
+        };
+
+        assert_eq!(small_vector_1.dot(&small_vector_2), 1.5);
+    }
+
+    #[test]
+    fn small_vector_float_scalar_and_sum() {
+        let small_vector: SmallVector<f32, 3> = SmallVector {
+            data: [0.5, 1.0, 1.5],
+        };
+
+        assert_eq!(small_vector.scalar(0.5).sum(), 1.5);
+    }
+
     #[test]
     fn small_vector_sum() {
         let small_vector: SmallVector<i8, 3> = SmallVector { data: [1, 2, 3] };